@@ -0,0 +1,228 @@
+//! Repeated timing of solutions, used by `aoc bench` and the per-day benches.
+
+use crate::{runner, solution::Part};
+use std::{
+    fmt::{self, Display},
+    hint::black_box,
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+/// Durations of repeated runs of the same code, sorted from fastest to slowest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Samples(Vec<Duration>);
+
+impl Samples {
+    /// Collects samples, in any order. Panics if there are none.
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort_unstable();
+        Self(samples)
+    }
+
+    pub fn runs(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.0[0]
+    }
+
+    /// The middle sample, or the mean of the two middle ones for an even count.
+    pub fn median(&self) -> Duration {
+        let middle = self.0.len() / 2;
+        if self.0.len().is_multiple_of(2) {
+            (self.0[middle - 1] + self.0[middle]) / 2
+        } else {
+            self.0[middle]
+        }
+    }
+
+    pub fn max(&self) -> Duration {
+        self.0[self.0.len() - 1]
+    }
+}
+
+/// Runs `f` `iterations` times (at least once) and times each run.
+///
+/// Results go through [`black_box`] so the work can't be optimised away.
+pub fn measure<T>(iterations: usize, mut f: impl FnMut() -> T) -> Samples {
+    let samples = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Samples::new(samples)
+}
+
+/// The part of a solution being timed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part(Part::One) => f.pad("part1"),
+            Stage::Part(Part::Two) => f.pad("part2"),
+        }
+    }
+}
+
+/// The samples taken for one stage of a day.
+#[derive(Debug, Clone)]
+pub struct Timing {
+    pub stage: Stage,
+    pub samples: Samples,
+}
+
+/// How [`write_report`] lays out its rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+impl Format {
+    /// Parses a format name: `table`, `csv` or `json`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "table" => Some(Format::Table),
+            "csv" => Some(Format::Csv),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Writes timings for several days in the given format.
+///
+/// CSV and JSON give durations in whole nanoseconds so they're easy to compare
+/// across commits; the table uses [`runner::format_duration`].
+pub fn write_report<'a>(
+    out: &mut impl Write,
+    format: Format,
+    rows: impl IntoIterator<Item = (&'a str, &'a Timing)>,
+) -> io::Result<()> {
+    let rows = rows.into_iter();
+    match format {
+        Format::Table => {
+            writeln!(
+                out,
+                "{:<5} {:<6} {:>6} {:>10} {:>10} {:>10}",
+                "DAY", "STAGE", "RUNS", "MIN", "MEDIAN", "MAX"
+            )?;
+            for (name, timing) in rows {
+                let samples = &timing.samples;
+                writeln!(
+                    out,
+                    "{:<5} {:<6} {:>6} {:>10} {:>10} {:>10}",
+                    name,
+                    timing.stage,
+                    samples.runs(),
+                    runner::format_duration(samples.min()),
+                    runner::format_duration(samples.median()),
+                    runner::format_duration(samples.max())
+                )?;
+            }
+        }
+        Format::Csv => {
+            writeln!(out, "day,stage,runs,min_ns,median_ns,max_ns")?;
+            for (name, timing) in rows {
+                let samples = &timing.samples;
+                writeln!(
+                    out,
+                    "{},{},{},{},{},{}",
+                    name,
+                    timing.stage,
+                    samples.runs(),
+                    samples.min().as_nanos(),
+                    samples.median().as_nanos(),
+                    samples.max().as_nanos()
+                )?;
+            }
+        }
+        Format::Json => {
+            write!(out, "[")?;
+            for (i, (name, timing)) in rows.enumerate() {
+                let samples = &timing.samples;
+                write!(
+                    out,
+                    "{}\n  {{\"day\": \"{}\", \"stage\": \"{}\", \"runs\": {}, \
+                     \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                    if i == 0 { "" } else { "," },
+                    name,
+                    timing.stage,
+                    samples.runs(),
+                    samples.min().as_nanos(),
+                    samples.median().as_nanos(),
+                    samples.max().as_nanos()
+                )?;
+            }
+            writeln!(out, "\n]")?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(stage: Stage, micros: &[u64]) -> Timing {
+        Timing {
+            stage,
+            samples: Samples::new(micros.iter().map(|&m| Duration::from_micros(m)).collect()),
+        }
+    }
+
+    #[test]
+    fn samples_summarise_unsorted_runs() {
+        let odd = timing(Stage::Parse, &[5, 1, 3]).samples;
+        assert_eq!(odd.min(), Duration::from_micros(1));
+        assert_eq!(odd.median(), Duration::from_micros(3));
+        assert_eq!(odd.max(), Duration::from_micros(5));
+
+        let even = timing(Stage::Parse, &[4, 1, 2, 8]).samples;
+        assert_eq!(even.median(), Duration::from_micros(3));
+    }
+
+    #[test]
+    fn measure_runs_at_least_once() {
+        let mut runs = 0;
+        assert_eq!(measure(0, || runs += 1).runs(), 1);
+        assert_eq!(runs, 1);
+    }
+
+    #[test]
+    fn reports_in_machine_readable_formats() {
+        let parse = timing(Stage::Parse, &[1, 2]);
+        let part2 = timing(Stage::Part(Part::Two), &[10]);
+        let rows = [("2v2", &parse), ("2v2", &part2)];
+
+        let mut csv = Vec::new();
+        write_report(&mut csv, Format::Csv, rows).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "day,stage,runs,min_ns,median_ns,max_ns\n\
+             2v2,parse,2,1000,1500,2000\n\
+             2v2,part2,1,10000,10000,10000\n"
+        );
+
+        let mut json = Vec::new();
+        write_report(&mut json, Format::Json, rows).unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            "[\n  {\"day\": \"2v2\", \"stage\": \"parse\", \"runs\": 2, \
+             \"min_ns\": 1000, \"median_ns\": 1500, \"max_ns\": 2000},\n  \
+             {\"day\": \"2v2\", \"stage\": \"part2\", \"runs\": 1, \
+             \"min_ns\": 10000, \"median_ns\": 10000, \"max_ns\": 10000}\n]\n"
+        );
+    }
+}
@@ -0,0 +1,1176 @@
+use crate::parse::{self, ParseError};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::hash_map::{
+    Entry, ExtractIf, IntoIter, Iter, IterMut, Keys, Values, ValuesMut,
+};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::hash::Hash;
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Index, IndexMut, Neg, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+/// A type a [`Counter`] can count in.
+///
+/// Implemented for the primitive integers and, with the `bigint` feature, for
+/// [`num_bigint::BigInt`] and [`num_bigint::BigUint`]. Counters never wrap:
+/// arithmetic that doesn't fit the count type panics, or is reported by the
+/// `checked_*` and `try_*` methods.
+pub trait Count: Clone + Ord + 'static {
+    /// A zero that lives forever, so missing keys can be indexed.
+    fn zero() -> &'static Self;
+
+    fn one() -> Self;
+
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+
+    /// The count as a number of repetitions, or [`None`] if it's negative or too large.
+    fn to_usize(&self) -> Option<usize>;
+}
+
+macro_rules! impl_count {
+    ($($ty:ty)+) => {
+        $(
+            impl Count for $ty {
+                #[inline]
+                fn zero() -> &'static Self {
+                    &0
+                }
+
+                #[inline]
+                fn one() -> Self {
+                    1
+                }
+
+                #[inline]
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$ty>::checked_add(*self, *other)
+                }
+
+                #[inline]
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    <$ty>::checked_sub(*self, *other)
+                }
+
+                #[inline]
+                fn to_usize(&self) -> Option<usize> {
+                    usize::try_from(*self).ok()
+                }
+            }
+        )+
+    };
+}
+
+impl_count!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+#[cfg(feature = "bigint")]
+mod bigint {
+    use super::Count;
+    use num_bigint::{BigInt, BigUint};
+
+    static INT_ZERO: BigInt = BigInt::ZERO;
+    static UINT_ZERO: BigUint = BigUint::ZERO;
+
+    impl Count for BigInt {
+        fn zero() -> &'static Self {
+            &INT_ZERO
+        }
+
+        fn one() -> Self {
+            1.into()
+        }
+
+        fn checked_add(&self, other: &Self) -> Option<Self> {
+            Some(self + other)
+        }
+
+        fn checked_sub(&self, other: &Self) -> Option<Self> {
+            Some(self - other)
+        }
+
+        fn to_usize(&self) -> Option<usize> {
+            self.try_into().ok()
+        }
+    }
+
+    impl Count for BigUint {
+        fn zero() -> &'static Self {
+            &UINT_ZERO
+        }
+
+        fn one() -> Self {
+            1u8.into()
+        }
+
+        fn checked_add(&self, other: &Self) -> Option<Self> {
+            Some(self + other)
+        }
+
+        fn checked_sub(&self, other: &Self) -> Option<Self> {
+            (self >= other).then(|| self - other)
+        }
+
+        fn to_usize(&self) -> Option<usize> {
+            self.try_into().ok()
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::Counter;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    impl<K, C> Serialize for Counter<K, C>
+    where
+        K: Serialize,
+        C: Serialize,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.data.serialize(serializer)
+        }
+    }
+
+    impl<'de, K, C> Deserialize<'de> for Counter<K, C>
+    where
+        K: Eq + Hash + Deserialize<'de>,
+        C: Deserialize<'de>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            HashMap::deserialize(deserializer).map(|data| Counter { data })
+        }
+    }
+}
+
+/// Returned when a count doesn't fit in the counter's count type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("count overflowed the counter's count type")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+const OVERFLOW: &str = "counter overflow";
+
+/// Adds to the count for `key`, inserting it first if needed.
+fn add_to<K, C>(data: &mut HashMap<K, C>, key: K, value: &C) -> Result<(), Overflow>
+where
+    K: Eq + Hash,
+    C: Count,
+{
+    match data.entry(key) {
+        Entry::Occupied(mut entry) => {
+            let sum = entry.get().checked_add(value).ok_or(Overflow)?;
+            entry.insert(sum);
+        }
+        Entry::Vacant(entry) => {
+            entry.insert(value.clone());
+        }
+    }
+    Ok(())
+}
+
+/// Higher counts first, then smaller keys.
+fn most_first<K: Ord, C: Ord>((lk, lv): &(&K, &C), (rk, rv): &(&K, &C)) -> Ordering {
+    rv.cmp(lv).then_with(|| lk.cmp(rk))
+}
+
+/// Lower counts first, then smaller keys.
+fn least_first<K: Ord, C: Ord>((lk, lv): &(&K, &C), (rk, rv): &(&K, &C)) -> Ordering {
+    lv.cmp(rv).then_with(|| lk.cmp(rk))
+}
+
+/// A counter modeled after Python's.
+///
+/// Counts are `i64` unless another [`Count`] type is picked.
+pub struct Counter<K, C = i64> {
+    data: HashMap<K, C>,
+}
+
+impl<K, C> Default for Counter<K, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, C> Counter<K, C> {
+    /// Creates an empty counter.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            data: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of elements in the counter.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the counter has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Computes a sum over the counter.
+    ///
+    /// Panics if the sum doesn't fit the count type, see [`checked_total`](Self::checked_total).
+    #[inline]
+    pub fn total(&self) -> C
+    where
+        C: Count,
+    {
+        self.checked_total().expect(OVERFLOW)
+    }
+
+    /// Computes a sum over the counter, or [`None`] if it doesn't fit the count type.
+    pub fn checked_total(&self) -> Option<C>
+    where
+        C: Count,
+    {
+        self.data
+            .values()
+            .try_fold(C::zero().clone(), |sum, value| sum.checked_add(value))
+    }
+
+    /// Returns the inner underlying HashMap storage.
+    #[inline]
+    pub fn into_inner(self) -> HashMap<K, C> {
+        self.data
+    }
+
+    /// Returns an iterator over the values
+    #[inline]
+    pub fn values<'a>(&'a self) -> Values<'a, K, C> {
+        self.data.values()
+    }
+
+    /// Returns an iterator over the keys
+    #[inline]
+    pub fn keys<'a>(&'a self) -> Keys<'a, K, C> {
+        self.data.keys()
+    }
+
+    /// Returns an iterator over the `(key, value)` pairs.
+    #[inline]
+    pub fn iter<'a>(&'a self) -> Iter<'a, K, C> {
+        self.data.iter()
+    }
+
+    /// Returns an iterator over the values mutably
+    #[inline]
+    pub fn values_mut<'a>(&'a mut self) -> ValuesMut<'a, K, C> {
+        self.data.values_mut()
+    }
+
+    /// Returns an iterator over the `(key, value)` pairs mutably.
+    #[inline]
+    pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a, K, C> {
+        self.data.iter_mut()
+    }
+
+    /// Returns a copy to the internal value corresponding to the key.
+    ///
+    /// If the key is not found then [`None`] is returned.
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<C>
+    where
+        K: Eq + Hash + Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+        C: Clone,
+    {
+        self.data.get(key).cloned()
+    }
+
+    /// Returns a mutable reference to the element pointed by the key,
+    /// inserting a zero count first if it's missing.
+    ///
+    /// Note that this is different from [`HashMap::entry`]. To avoid building
+    /// an owned key when it's already counted, index mutably instead:
+    /// `counter[key] += 1` only clones `key` to insert it.
+    #[inline]
+    pub fn entry(&mut self, key: K) -> &mut C
+    where
+        K: Eq + Hash,
+        C: Count,
+    {
+        self.data.entry(key).or_insert_with(|| C::zero().clone())
+    }
+
+    /// Removes a key from the counter.
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<C>
+    where
+        K: Eq + Hash + Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.data.remove(key)
+    }
+
+    /// Clears the counter but retains memory
+    #[inline]
+    pub fn clear(&mut self) {
+        self.data.clear()
+    }
+
+    /// Returns the most common elements in the counter.
+    ///
+    /// These values are sorted in descending order. Ties come out in no
+    /// particular order, see [`most_common_ordered`](Self::most_common_ordered).
+    pub fn most_common(&self) -> Vec<(K, C)>
+    where
+        K: Clone,
+        C: Ord + Clone,
+    {
+        self.take_most_common(self.len())
+    }
+
+    /// Returns the least common elements in the counter.
+    ///
+    /// These values are sorted in ascending order. Ties come out in no
+    /// particular order, see [`least_common_ordered`](Self::least_common_ordered).
+    pub fn least_common(&self) -> Vec<(K, C)>
+    where
+        K: Clone,
+        C: Ord + Clone,
+    {
+        self.take_least_common(self.len())
+    }
+
+    /// Returns the top common element in the counter
+    pub fn top(&self) -> Option<(K, C)>
+    where
+        K: Clone,
+        C: Ord + Clone,
+    {
+        self.data
+            .iter()
+            .max_by_key(|&(_, v)| v)
+            .map(|(k, v)| (k.clone(), v.clone()))
+    }
+
+    /// Returns the bottom common element in the counter
+    pub fn bottom(&self) -> Option<(K, C)>
+    where
+        K: Clone,
+        C: Ord + Clone,
+    {
+        self.data
+            .iter()
+            .min_by_key(|&(_, v)| v)
+            .map(|(k, v)| (k.clone(), v.clone()))
+    }
+
+    /// Returns the N most common elements in the counter.
+    ///
+    /// Ordered like [`most_common`](Self::most_common), but only the N are sorted.
+    pub fn take_most_common(&self, count: usize) -> Vec<(K, C)>
+    where
+        K: Clone,
+        C: Ord + Clone,
+    {
+        self.take_by(count, |(_, lhs), (_, rhs)| rhs.cmp(lhs))
+    }
+
+    /// Returns the N least common elements in the counter.
+    ///
+    /// Ordered like [`least_common`](Self::least_common), but only the N are sorted.
+    pub fn take_least_common(&self, count: usize) -> Vec<(K, C)>
+    where
+        K: Clone,
+        C: Ord + Clone,
+    {
+        self.take_by(count, |(_, lhs), (_, rhs)| lhs.cmp(rhs))
+    }
+
+    /// Like [`most_common`](Self::most_common), but ties are broken by
+    /// ascending key so the order is the same on every run.
+    pub fn most_common_ordered(&self) -> Vec<(K, C)>
+    where
+        K: Ord + Clone,
+        C: Ord + Clone,
+    {
+        self.take_most_common_ordered(self.len())
+    }
+
+    /// Like [`least_common`](Self::least_common), but ties are broken by
+    /// ascending key.
+    pub fn least_common_ordered(&self) -> Vec<(K, C)>
+    where
+        K: Ord + Clone,
+        C: Ord + Clone,
+    {
+        self.take_least_common_ordered(self.len())
+    }
+
+    /// Like [`top`](Self::top), but the smallest key wins a tie.
+    pub fn top_ordered(&self) -> Option<(K, C)>
+    where
+        K: Ord + Clone,
+        C: Ord + Clone,
+    {
+        self.data
+            .iter()
+            .min_by(|lhs, rhs| most_first(lhs, rhs))
+            .map(|(k, v)| (k.clone(), v.clone()))
+    }
+
+    /// Like [`bottom`](Self::bottom), but the smallest key wins a tie.
+    pub fn bottom_ordered(&self) -> Option<(K, C)>
+    where
+        K: Ord + Clone,
+        C: Ord + Clone,
+    {
+        self.data
+            .iter()
+            .min_by(|lhs, rhs| least_first(lhs, rhs))
+            .map(|(k, v)| (k.clone(), v.clone()))
+    }
+
+    /// Like [`take_most_common`](Self::take_most_common), but ties are broken
+    /// by ascending key.
+    pub fn take_most_common_ordered(&self, count: usize) -> Vec<(K, C)>
+    where
+        K: Ord + Clone,
+        C: Ord + Clone,
+    {
+        self.take_by(count, most_first)
+    }
+
+    /// Like [`take_least_common`](Self::take_least_common), but ties are
+    /// broken by ascending key.
+    pub fn take_least_common_ordered(&self, count: usize) -> Vec<(K, C)>
+    where
+        K: Ord + Clone,
+        C: Ord + Clone,
+    {
+        self.take_by(count, least_first)
+    }
+
+    /// The first `count` elements in `order`, selecting them before sorting
+    /// so a small `count` doesn't pay for sorting the whole counter.
+    fn take_by(
+        &self,
+        count: usize,
+        order: impl Fn(&(&K, &C), &(&K, &C)) -> Ordering + Copy,
+    ) -> Vec<(K, C)>
+    where
+        K: Clone,
+        C: Clone,
+    {
+        if count == 0 {
+            return Vec::new();
+        }
+        let mut values: Vec<_> = self.data.iter().collect();
+        if count < values.len() {
+            values.select_nth_unstable_by(count - 1, order);
+            values.truncate(count);
+        }
+        values.sort_unstable_by(order);
+        values
+            .into_iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    }
+
+    /// Adds `(key, count)` pairs, stopping at the first count that overflows.
+    ///
+    /// The pairs before it stay added.
+    pub fn try_extend<T>(&mut self, iter: T) -> Result<(), Overflow>
+    where
+        T: IntoIterator<Item = (K, C)>,
+        K: Eq + Hash,
+        C: Count,
+    {
+        iter.into_iter()
+            .try_for_each(|(obj, value)| add_to(&mut self.data, obj, &value))
+    }
+
+    /// Adds the counts from `other`, keeping zero and negative results.
+    ///
+    /// Unlike `+`, nothing is removed from the counter. Panics on overflow,
+    /// see [`try_update`](Self::try_update).
+    pub fn update(&mut self, other: &Counter<K, C>)
+    where
+        K: Eq + Hash + Clone,
+        C: Count,
+    {
+        self.try_update(other).expect(OVERFLOW)
+    }
+
+    /// Like [`update`](Self::update), but leaves the counter unchanged and
+    /// returns an error if any count would overflow.
+    pub fn try_update(&mut self, other: &Counter<K, C>) -> Result<(), Overflow>
+    where
+        K: Eq + Hash + Clone,
+        C: Count,
+    {
+        self.apply(other, |lhs, rhs| lhs.checked_add(rhs))
+    }
+
+    /// Subtracts the counts in `other`, keeping zero and negative results.
+    ///
+    /// Unlike `-`, nothing is removed from the counter. Panics on overflow,
+    /// which for unsigned counts includes going below zero, see
+    /// [`try_subtract`](Self::try_subtract).
+    pub fn subtract(&mut self, other: &Counter<K, C>)
+    where
+        K: Eq + Hash + Clone,
+        C: Count,
+    {
+        self.try_subtract(other).expect(OVERFLOW)
+    }
+
+    /// Like [`subtract`](Self::subtract), but leaves the counter unchanged and
+    /// returns an error if any count would overflow.
+    pub fn try_subtract(&mut self, other: &Counter<K, C>) -> Result<(), Overflow>
+    where
+        K: Eq + Hash + Clone,
+        C: Count,
+    {
+        self.apply(other, |lhs, rhs| lhs.checked_sub(rhs))
+    }
+
+    /// Works out `f(count, other_count)` for every key in `other`, then stores
+    /// the results if none of them overflowed.
+    fn apply(
+        &mut self,
+        other: &Counter<K, C>,
+        f: impl Fn(&C, &C) -> Option<C>,
+    ) -> Result<(), Overflow>
+    where
+        K: Eq + Hash + Clone,
+        C: Count,
+    {
+        let results = other
+            .data
+            .iter()
+            .map(|(key, value)| {
+                let current = self.data.get(key).unwrap_or(C::zero());
+                Ok((key.clone(), f(current, value).ok_or(Overflow)?))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.data.extend(results);
+        Ok(())
+    }
+
+    /// Removes the elements with a zero or negative count.
+    ///
+    /// This is Python's unary `+`, which Rust has no operator for.
+    pub fn positive(mut self) -> Self
+    where
+        C: Count,
+    {
+        self.remove_non_positive();
+        self
+    }
+
+    /// Removes the elements with a zero or negative count in place.
+    pub fn remove_non_positive(&mut self)
+    where
+        C: Count,
+    {
+        self.retain(|_, v| &*v > C::zero());
+    }
+
+    /// Keeps only the elements for which `f` returns `true`.
+    #[inline]
+    pub fn retain(&mut self, f: impl FnMut(&K, &mut C) -> bool) {
+        self.data.retain(f)
+    }
+
+    /// Removes and yields the elements for which `f` returns `true`.
+    ///
+    /// Elements are only removed as the iterator reaches them, so dropping it
+    /// early leaves the rest in the counter.
+    #[inline]
+    pub fn extract_if<F>(&mut self, f: F) -> ExtractIf<'_, K, C, F>
+    where
+        F: FnMut(&K, &mut C) -> bool,
+    {
+        self.data.extract_if(f)
+    }
+
+    /// Sets each count to `f(count, other_count)`, treating missing counts as
+    /// zero, then removes the elements that didn't end up positive.
+    ///
+    /// `f` returns [`None`] for results it already knows aren't positive.
+    fn combine(&mut self, other: &Counter<K, C>, f: fn(&C, &C) -> Option<C>)
+    where
+        K: Eq + Hash + Clone,
+        C: Count,
+    {
+        for key in other.data.keys() {
+            if !self.data.contains_key(key) {
+                self.data.insert(key.clone(), C::zero().clone());
+            }
+        }
+
+        self.data = std::mem::take(&mut self.data)
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let other = other.data.get(&key).unwrap_or(C::zero());
+                f(&value, other)
+                    .filter(|result| result > C::zero())
+                    .map(|result| (key, result))
+            })
+            .collect();
+    }
+
+    /// Return an iterator over elements repeating each as many times as its count.
+    ///
+    /// Elements are returned in no particular order.
+    /// If an element’s count is less than one, it'll be ignored.
+    ///
+    /// Panics if a count is too large to be a `usize`.
+    pub fn elements(&self) -> impl Iterator<Item = &K>
+    where
+        C: Count,
+    {
+        self.iter().flat_map(|(k, v)| {
+            let times = if v > C::zero() {
+                v.to_usize().expect(OVERFLOW)
+            } else {
+                0
+            };
+            std::iter::repeat_n(k, times)
+        })
+    }
+}
+
+impl<'a, K, C> IntoIterator for &'a Counter<K, C> {
+    type Item = (&'a K, &'a C);
+    type IntoIter = Iter<'a, K, C>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, K, C> {
+        self.iter()
+    }
+}
+
+impl<'a, K, C> IntoIterator for &'a mut Counter<K, C> {
+    type Item = (&'a K, &'a mut C);
+    type IntoIter = IterMut<'a, K, C>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, K, C> {
+        self.iter_mut()
+    }
+}
+
+impl<K, C> IntoIterator for Counter<K, C> {
+    type Item = (K, C);
+    type IntoIter = IntoIter<K, C>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<K, C> {
+        self.data.into_iter()
+    }
+}
+
+impl<K, C> FromIterator<K> for Counter<K, C>
+where
+    K: Eq + Hash,
+    C: Count,
+{
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = K>,
+    {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<K, C> std::fmt::Debug for Counter<K, C>
+where
+    K: fmt::Debug,
+    C: fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.data.fmt(f)
+    }
+}
+
+/// Prints one `key: count` line per element, sorted by key so the output is
+/// stable enough to diff. [`FromStr`] reads it back.
+impl<K, C> Display for Counter<K, C>
+where
+    K: Ord + Display,
+    C: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut entries: Vec<_> = self.data.iter().collect();
+        entries.sort_unstable_by_key(|&(key, _)| key);
+        for (key, count) in entries {
+            writeln!(f, "{}: {}", key, count)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses `key: count` lines, adding up the counts of repeated keys.
+impl<K, C> FromStr for Counter<K, C>
+where
+    K: Eq + Hash + FromStr,
+    K::Err: Display,
+    C: Count + FromStr,
+    C::Err: Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut counter = Self::new();
+        for line in s.lines() {
+            let add = |counter: &mut Self| {
+                let (key, text) = line
+                    .rsplit_once(": ")
+                    .ok_or_else(|| ParseError::new("expected `key: count`", line))?;
+                let key = parse::value(key).map_err(|e| e.within(line, key))?;
+                let count: C = parse::value(text).map_err(|e| e.within(line, text))?;
+                add_to(&mut counter.data, key, &count)
+                    .map_err(|error| ParseError::new(error.to_string(), text).within(line, text))
+            };
+            add(&mut counter).map_err(|e| e.within(s, line))?;
+        }
+        Ok(counter)
+    }
+}
+
+impl<K, C> Clone for Counter<K, C>
+where
+    K: Clone,
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.data.clone_from(&source.data);
+    }
+}
+
+impl<K, C> PartialEq for Counter<K, C>
+where
+    K: Eq + Hash,
+    C: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<K, C> Eq for Counter<K, C>
+where
+    K: Eq + Hash,
+    C: Eq,
+{
+}
+
+impl<K, C, const N: usize> From<[K; N]> for Counter<K, C>
+where
+    K: Eq + Hash,
+    C: Count,
+{
+    fn from(arr: [K; N]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<K, C> Extend<K> for Counter<K, C>
+where
+    K: Eq + Hash,
+    C: Count,
+{
+    fn extend<T: IntoIterator<Item = K>>(&mut self, iter: T) {
+        let one = C::one();
+        for obj in iter {
+            add_to(&mut self.data, obj, &one).expect(OVERFLOW);
+        }
+    }
+}
+
+impl<K, C> FromIterator<(K, C)> for Counter<K, C>
+where
+    K: Eq + Hash,
+    C: Count,
+{
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = (K, C)>,
+    {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<K, C> Extend<(K, C)> for Counter<K, C>
+where
+    K: Eq + Hash,
+    C: Count,
+{
+    fn extend<T: IntoIterator<Item = (K, C)>>(&mut self, iter: T) {
+        self.try_extend(iter).expect(OVERFLOW)
+    }
+}
+
+impl<K, C, const N: usize> From<[(K, C); N]> for Counter<K, C>
+where
+    K: Eq + Hash,
+    C: Count,
+{
+    fn from(arr: [(K, C); N]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<K, C, Q> Index<&Q> for Counter<K, C>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: ?Sized + Eq + Hash,
+    C: Count,
+{
+    type Output = C;
+
+    fn index(&self, index: &Q) -> &Self::Output {
+        self.data.get(index).unwrap_or(C::zero())
+    }
+}
+
+/// Inserts a zero count for missing keys, so `counter[key] += 1` always works.
+impl<K, C, Q> IndexMut<&Q> for Counter<K, C>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: ?Sized + Eq + Hash + ToOwned<Owned = K>,
+    C: Count,
+{
+    fn index_mut(&mut self, index: &Q) -> &mut Self::Output {
+        if !self.data.contains_key(index) {
+            self.data.insert(index.to_owned(), C::zero().clone());
+        }
+        self.data.get_mut(index).expect("key was just inserted")
+    }
+}
+
+macro_rules! impl_operators {
+    ($($op:ident $method:ident $assign:ident $assign_method:ident => $f:expr;)+) => {
+        $(
+            impl<K, C> $assign<&Counter<K, C>> for Counter<K, C>
+            where
+                K: Eq + Hash + Clone,
+                C: Count,
+            {
+                fn $assign_method(&mut self, rhs: &Counter<K, C>) {
+                    self.combine(rhs, $f);
+                }
+            }
+
+            impl<K, C> $assign for Counter<K, C>
+            where
+                K: Eq + Hash + Clone,
+                C: Count,
+            {
+                fn $assign_method(&mut self, rhs: Counter<K, C>) {
+                    self.combine(&rhs, $f);
+                }
+            }
+
+            impl<K, C> $op<&Counter<K, C>> for Counter<K, C>
+            where
+                K: Eq + Hash + Clone,
+                C: Count,
+            {
+                type Output = Counter<K, C>;
+
+                fn $method(mut self, rhs: &Counter<K, C>) -> Counter<K, C> {
+                    self.combine(rhs, $f);
+                    self
+                }
+            }
+
+            impl<K, C> $op for Counter<K, C>
+            where
+                K: Eq + Hash + Clone,
+                C: Count,
+            {
+                type Output = Counter<K, C>;
+
+                fn $method(mut self, rhs: Counter<K, C>) -> Counter<K, C> {
+                    self.combine(&rhs, $f);
+                    self
+                }
+            }
+
+            impl<K, C> $op<&Counter<K, C>> for &Counter<K, C>
+            where
+                K: Eq + Hash + Clone,
+                C: Count,
+            {
+                type Output = Counter<K, C>;
+
+                fn $method(self, rhs: &Counter<K, C>) -> Counter<K, C> {
+                    let mut result = self.clone();
+                    result.combine(rhs, $f);
+                    result
+                }
+            }
+        )+
+    };
+}
+
+// Like Python, every operator only keeps the elements that end up positive.
+// They panic if a count overflows.
+impl_operators! {
+    Add add AddAssign add_assign => |lhs, rhs| Some(lhs.checked_add(rhs).expect(OVERFLOW));
+    Sub sub SubAssign sub_assign => |lhs, rhs| {
+        (lhs > rhs).then(|| lhs.checked_sub(rhs).expect(OVERFLOW))
+    };
+    BitAnd bitand BitAndAssign bitand_assign => |lhs, rhs| Some(lhs.min(rhs).clone());
+    BitOr bitor BitOrAssign bitor_assign => |lhs, rhs| Some(lhs.max(rhs).clone());
+}
+
+/// Python's unary `-`: the negated negative counts, with everything else removed.
+impl<K, C> Neg for Counter<K, C>
+where
+    C: Count + Neg<Output = C>,
+{
+    type Output = Counter<K, C>;
+
+    fn neg(mut self) -> Counter<K, C> {
+        self.data.retain(|_, v| &*v < C::zero());
+        for value in self.data.values_mut() {
+            *value = -value.clone();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counter(pairs: &[(char, i64)]) -> Counter<char> {
+        pairs.iter().copied().collect()
+    }
+
+    #[test]
+    fn operators_keep_positive_counts() {
+        let c = counter(&[('a', 3), ('b', 1)]);
+        let d = counter(&[('a', 1), ('b', 2)]);
+
+        assert_eq!(&c + &d, counter(&[('a', 4), ('b', 3)]));
+        assert_eq!(&c - &d, counter(&[('a', 2)]));
+        assert_eq!(&c & &d, counter(&[('a', 1), ('b', 1)]));
+        assert_eq!(&c | &d, counter(&[('a', 3), ('b', 2)]));
+    }
+
+    #[test]
+    fn assign_operators_match_binary_ones() {
+        let c = counter(&[('a', 3), ('b', -1), ('c', 2)]);
+        let d = counter(&[('a', 5), ('d', 1)]);
+
+        let mut sum = c.clone();
+        sum += &d;
+        assert_eq!(sum, c.clone() + d.clone());
+        assert_eq!(sum, counter(&[('a', 8), ('c', 2), ('d', 1)]));
+
+        let mut difference = c.clone();
+        difference -= d.clone();
+        assert_eq!(difference, counter(&[('c', 2)]));
+
+        let mut intersection = c.clone();
+        intersection &= &d;
+        assert_eq!(intersection, counter(&[('a', 3)]));
+
+        let mut union = c.clone();
+        union |= d;
+        assert_eq!(union, counter(&[('a', 5), ('c', 2), ('d', 1)]));
+    }
+
+    #[test]
+    fn update_and_subtract_keep_everything() {
+        let mut c = counter(&[('a', 1)]);
+        c.subtract(&counter(&[('a', 1), ('b', 2)]));
+        assert_eq!(c, counter(&[('a', 0), ('b', -2)]));
+
+        c.update(&counter(&[('b', 1), ('c', 1)]));
+        assert_eq!(c, counter(&[('a', 0), ('b', -1), ('c', 1)]));
+
+        assert_eq!(c.clone().positive(), counter(&[('c', 1)]));
+        assert_eq!(-c, counter(&[('b', 1)]));
+    }
+
+    #[test]
+    fn owned_keys_can_be_counted_in_place() {
+        let mut words: Counter<String> = Counter::new();
+        for word in "the cat saw the dog".split(' ') {
+            words[word] += 1;
+        }
+        *words.entry("cat".to_string()) -= 2;
+        assert_eq!((words["the"], words["cat"], words["fish"]), (2, -1, 0));
+
+        words["fish"] -= 1;
+        let mut removed: Vec<_> = words.extract_if(|_, v| *v < 0).collect();
+        removed.sort();
+        assert_eq!(removed, [("cat".to_string(), -1), ("fish".to_string(), -1)]);
+
+        words["saw"] = 0;
+        words.remove_non_positive();
+        assert_eq!(
+            words.least_common(),
+            [("dog".to_string(), 1), ("the".to_string(), 2)]
+        );
+
+        words.retain(|word, _| word.starts_with('t'));
+        assert_eq!(words.len(), 1);
+    }
+
+    #[test]
+    fn text_round_trips() {
+        let c = counter(&[('b', -2), ('a', 3), ('c', 0)]);
+        let text = c.to_string();
+        assert_eq!(text, "a: 3\nb: -2\nc: 0\n");
+        assert_eq!(text.parse::<Counter<char>>().unwrap(), c);
+
+        let repeated: Counter<String, u8> = "x: 200\ny: 1\nx: 55".parse().unwrap();
+        assert_eq!(repeated["x"], 255);
+
+        let error = "x: 200\nx: 56".parse::<Counter<String, u8>>().unwrap_err();
+        assert_eq!(
+            (error.message(), error.text(), error.line(), error.column()),
+            (Overflow.to_string().as_str(), "56", 2, 4)
+        );
+        let error = "x: 1\ny 2".parse::<Counter<String>>().unwrap_err();
+        assert_eq!(
+            (error.message(), error.line()),
+            ("expected `key: count`", 2)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        let c = counter(&[('a', 3), ('b', -1)]);
+        let json = serde_json::to_string(&c).unwrap();
+        assert_eq!(serde_json::from_str::<Counter<char>>(&json).unwrap(), c);
+        assert_eq!(
+            serde_json::from_str::<Counter<char>>(r#"{"z": 4}"#).unwrap(),
+            counter(&[('z', 4)])
+        );
+    }
+
+    #[test]
+    fn ties_are_broken_by_key() {
+        let c = counter(&[('d', 2), ('b', 1), ('a', 2), ('c', 1), ('e', 3)]);
+
+        assert_eq!(
+            c.most_common_ordered(),
+            [('e', 3), ('a', 2), ('d', 2), ('b', 1), ('c', 1)]
+        );
+        assert_eq!(
+            c.least_common_ordered(),
+            [('b', 1), ('c', 1), ('a', 2), ('d', 2), ('e', 3)]
+        );
+        assert_eq!(c.take_most_common_ordered(2), [('e', 3), ('a', 2)]);
+        assert_eq!(
+            c.take_least_common_ordered(3),
+            [('b', 1), ('c', 1), ('a', 2)]
+        );
+        assert_eq!(c.take_most_common_ordered(0), []);
+        assert_eq!(c.take_most_common_ordered(9), c.most_common_ordered());
+        assert_eq!(c.top_ordered(), Some(('e', 3)));
+        assert_eq!(c.bottom_ordered(), Some(('b', 1)));
+        assert_eq!(Counter::<char>::new().top_ordered(), None);
+    }
+
+    #[test]
+    fn unordered_keys_can_be_ranked() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        struct Key(char);
+
+        let c: Counter<Key> = "abbccc".chars().map(Key).collect();
+        assert_eq!(c.top(), Some((Key('c'), 3)));
+        assert_eq!(c.bottom(), Some((Key('a'), 1)));
+        assert_eq!(c.take_most_common(1), [(Key('c'), 3)]);
+        assert_eq!(
+            c.least_common(),
+            [(Key('a'), 1), (Key('b'), 2), (Key('c'), 3)]
+        );
+
+        let counts: Vec<_> = c.most_common().into_iter().map(|(_, v)| v).collect();
+        assert_eq!(counts, [3, 2, 1]);
+    }
+
+    #[test]
+    fn counts_in_other_types() {
+        let mut small: Counter<char, u8> = "aab".chars().collect();
+        assert_eq!((small[&'a'], small[&'z'], small.total()), (2, 0, 3));
+
+        let big: Counter<char, u8> = Counter::from([('a', 254)]);
+        assert_eq!(small.try_update(&big), Err(Overflow));
+        assert_eq!(small[&'a'], 2);
+        assert_eq!(
+            small.try_subtract(&Counter::from([('b', 2)])),
+            Err(Overflow)
+        );
+        assert_eq!(
+            &small - &Counter::from([('b', 2)]),
+            Counter::from([('a', 2)])
+        );
+
+        let wide: Counter<char, u128> = Counter::from([('a', u128::MAX - 1), ('b', 1)]);
+        assert_eq!(wide.checked_total(), Some(u128::MAX));
+        let more: Counter<char, u128> = Counter::from([('b', 1)]);
+        assert_eq!((&wide + &more).checked_total(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "counter overflow")]
+    fn operators_panic_on_overflow() {
+        let c: Counter<char> = Counter::from([('a', i64::MAX)]);
+        let _ = &c + &c;
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn counts_exactly_with_big_integers() {
+        use num_bigint::BigUint;
+
+        // Lanternfish for 1000 days far exceeds a u128.
+        let mut fish: Counter<u8, BigUint> = [3, 4, 3, 1, 2].into_iter().collect();
+        for _ in 0..1000 {
+            fish = fish
+                .into_iter()
+                .flat_map(|(timer, count)| match timer {
+                    0 => vec![(6, count.clone()), (8, count)],
+                    _ => vec![(timer - 1, count)],
+                })
+                .collect();
+        }
+
+        let total = fish.total();
+        assert!(total > BigUint::from(u128::MAX));
+        assert_eq!(total.to_string().len(), 39);
+        assert_eq!(fish[&9], BigUint::ZERO);
+    }
+}
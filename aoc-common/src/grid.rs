@@ -0,0 +1,598 @@
+use crate::parse::{self, ParseError};
+use std::{
+    fmt::{Debug, Display, Write},
+    hash::Hash,
+    ops::{Index, IndexMut},
+};
+
+/// Boilerplate for grid-related problems
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<V> {
+    data: Vec<V>,
+    width: usize,
+    height: usize,
+}
+
+const CARDINAL: [(isize, isize); 4] = [(0, 1), (0, -1), (-1, 0), (1, 0)];
+
+const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
+
+const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// The direction to go towards.
+///
+/// Used in a few functions, mainly with traversal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    /// The cardinal directions (North, South, East, West)
+    Cardinal,
+    /// The diagonal directions (North West, North East, South West, South East)
+    Diagonal,
+    /// All adjacent directions (NW, N, NE, W, E, SW, S, SE)
+    Adjacent,
+}
+
+impl Direction {
+    fn as_iter(self) -> std::slice::Iter<'static, (isize, isize)> {
+        match self {
+            Direction::Cardinal => CARDINAL.iter(),
+            Direction::Diagonal => DIAGONAL.iter(),
+            Direction::Adjacent => ADJACENT.iter(),
+        }
+    }
+}
+
+impl<V> Grid<V> {
+    pub fn with_data(data: Vec<V>, width: usize, height: usize) -> Self {
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
+    #[inline]
+    const fn index(&self, x: usize, y: usize) -> usize {
+        (y * self.width) + x
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&V> {
+        if x < self.width && y < self.height {
+            // safety: already bound check
+            unsafe { Some(self.data.get_unchecked(self.index(x, y))) }
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut V> {
+        if x < self.width && y < self.height {
+            // safety: already bound check
+            let index = self.index(x, y);
+            unsafe { Some(self.data.get_unchecked_mut(index)) }
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the value at `(x, y)`, returning the old one.
+    ///
+    /// Returns [`None`] and drops `value` if the position is outside the grid.
+    pub fn set(&mut self, x: usize, y: usize, value: V) -> Option<V> {
+        self.get_mut(x, y)
+            .map(|current| std::mem::replace(current, value))
+    }
+
+    /// Whether a possibly negative position lies inside the grid.
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn neighbors(&self, x: usize, y: usize, direction: Direction) -> Directional {
+        Directional {
+            x: x as isize,
+            y: y as isize,
+            width: self.width as isize,
+            height: self.height as isize,
+            iter: direction.as_iter(),
+        }
+    }
+
+    /// Like [`neighbors`](Self::neighbors), but keeps positions that fall
+    /// outside the grid, which is why they're signed.
+    pub fn neighbors_unbounded(
+        &self,
+        x: isize,
+        y: isize,
+        direction: Direction,
+    ) -> impl Iterator<Item = (isize, isize)> {
+        direction.as_iter().map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    pub fn coordinates(&self) -> Coordinates {
+        Coordinates::new(self.width, self.height)
+    }
+
+    pub fn as_vec(&self) -> &Vec<V> {
+        &self.data
+    }
+
+    pub fn as_vec_mut(&mut self) -> &mut Vec<V> {
+        &mut self.data
+    }
+
+    pub fn items<'a>(&'a self) -> Items<'a, V> {
+        Items {
+            iter: self.data.iter(),
+            coordinates: self.coordinates(),
+        }
+    }
+
+    pub fn items_mut<'a>(&'a mut self) -> ItemsMut<'a, V> {
+        ItemsMut {
+            coordinates: self.coordinates(),
+            iter: self.data.iter_mut(),
+        }
+    }
+}
+
+impl<V> Grid<V> {
+    /// Builds a grid by calling `f` with every position, in reading order.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> V) -> Self {
+        Self {
+            data: Coordinates::new(width, height)
+                .map(|(x, y)| f(x, y))
+                .collect(),
+            width,
+            height,
+        }
+    }
+
+    /// Repeats the grid `nx` times across and `ny` times down, mapping each value
+    /// with the tile it ends up in, counted from `(0, 0)` at the top left.
+    pub fn tile<U>(&self, nx: usize, ny: usize, f: impl Fn(&V, usize, usize) -> U) -> Grid<U> {
+        Grid::from_fn(self.width * nx, self.height * ny, |x, y| {
+            f(
+                &self[(x % self.width, y % self.height)],
+                x / self.width,
+                y / self.height,
+            )
+        })
+    }
+
+    /// A read-only window onto part of the grid, or [`None`] if the rectangle
+    /// doesn't fit inside it.
+    pub fn view(&self, x: usize, y: usize, width: usize, height: usize) -> Option<View<'_, V>> {
+        if x.checked_add(width).is_none_or(|end| end > self.width)
+            || y.checked_add(height).is_none_or(|end| end > self.height)
+        {
+            return None;
+        }
+
+        Some(View {
+            grid: self,
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+}
+
+/// Transforms that copy the grid's values into a new layout.
+impl<V> Grid<V>
+where
+    V: Clone,
+{
+    /// Swaps rows and columns, mirroring along the top-left to bottom-right diagonal.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_90(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        Self::from_fn(self.width, self.height, |x, y| {
+            self[(self.width - 1 - x, self.height - 1 - y)].clone()
+        })
+    }
+
+    /// Rotates a quarter turn anticlockwise.
+    pub fn rotate_270(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_fn(self.width, self.height, |x, y| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_fn(self.width, self.height, |x, y| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+
+    /// Copies out part of the grid, see [`view`](Self::view).
+    pub fn crop(&self, x: usize, y: usize, width: usize, height: usize) -> Option<Self> {
+        self.view(x, y, width, height).map(|view| view.to_grid())
+    }
+}
+
+impl<V> Grid<V>
+where
+    V: Clone + Default,
+{
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            data: vec![Default::default(); width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<V> Index<(usize, usize)> for Grid<V> {
+    type Output = V;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1)
+            .expect("position is outside the grid")
+    }
+}
+
+impl<V> IndexMut<(usize, usize)> for Grid<V> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(index.0, index.1)
+            .expect("position is outside the grid")
+    }
+}
+
+/// A rectangular part of a [`Grid`], with positions relative to its top left.
+pub struct View<'a, V> {
+    grid: &'a Grid<V>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, V> View<'a, V> {
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a V> {
+        if x < self.width && y < self.height {
+            self.grid.get(self.x + x, self.y + y)
+        } else {
+            None
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn coordinates(&self) -> Coordinates {
+        Coordinates::new(self.width, self.height)
+    }
+
+    pub fn to_grid(&self) -> Grid<V>
+    where
+        V: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |x, y| self[(x, y)].clone())
+    }
+}
+
+impl<V> Index<(usize, usize)> for View<'_, V> {
+    type Output = V;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1)
+            .expect("position is outside the view")
+    }
+}
+
+pub trait FromCell
+where
+    Self: Sized,
+{
+    fn from_cell(c: char) -> Option<Self>;
+}
+
+impl<V> Grid<V>
+where
+    V: FromCell,
+{
+    pub fn from_cells(s: &str) -> Option<Self> {
+        let data: Option<Vec<V>> = s
+            .lines()
+            .flat_map(|x| x.chars())
+            .map(|c| V::from_cell(c))
+            .collect();
+
+        let data = data?;
+        let height = s.lines().count();
+        let width = s.find('\n').unwrap_or_else(|| data.len() / height);
+
+        Some(Self {
+            data,
+            width,
+            height,
+        })
+    }
+}
+
+pub struct Directional {
+    x: isize,
+    y: isize,
+    width: isize,
+    height: isize,
+    iter: std::slice::Iter<'static, (isize, isize)>,
+}
+
+impl Iterator for Directional {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (dx, dy) = self.iter.next()?;
+            let (x, y) = (self.x + dx, self.y + dy);
+            if x < 0 || y < 0 || x >= self.width || y >= self.height {
+                continue;
+            }
+
+            break Some((x as usize, y as usize));
+        }
+    }
+}
+
+pub struct Coordinates {
+    width: usize,
+    height: usize,
+    x: usize,
+    y: usize,
+}
+
+impl Coordinates {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            x: 0,
+            y: 0,
+        }
+    }
+}
+
+impl Iterator for Coordinates {
+    type Item = (usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.x >= self.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        if self.y >= self.height {
+            None
+        } else {
+            let result = (self.x, self.y);
+            self.x += 1;
+            Some(result)
+        }
+    }
+}
+
+pub struct Items<'a, V> {
+    iter: std::slice::Iter<'a, V>,
+    coordinates: Coordinates,
+}
+
+impl<'a, V> Iterator for Items<'a, V> {
+    type Item = ((usize, usize), &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.coordinates.next()?;
+        let item = self.iter.next()?;
+        Some(((x, y), item))
+    }
+}
+
+pub struct ItemsMut<'a, V> {
+    iter: std::slice::IterMut<'a, V>,
+    coordinates: Coordinates,
+}
+
+impl<'a, V> Iterator for ItemsMut<'a, V> {
+    type Item = ((usize, usize), &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.coordinates.next()?;
+        let item = self.iter.next()?;
+        Some(((x, y), item))
+    }
+}
+
+impl Grid<u8> {
+    /// A new grid that's separated by newlines and only a single ASCII digit.
+    ///
+    /// Every row must have the same width as the first one.
+    pub fn single_ascii_number(input: &str) -> Result<Self, ParseError> {
+        let mut data = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let row = parse::digits(line).map_err(|e| e.within(input, line))?;
+            if *width.get_or_insert(row.len()) != row.len() {
+                let message = format!("expected a row of {} digits", width.unwrap_or_default());
+                return Err(ParseError::new(message, line).within(input, line));
+            }
+
+            data.extend(row);
+            height += 1;
+        }
+
+        Ok(Self {
+            data,
+            width: width.unwrap_or_default(),
+            height,
+        })
+    }
+}
+
+impl<V> Debug for Grid<V>
+where
+    V: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Grid")
+            .field("data", &self.data)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+impl<V> Display for Grid<V>
+where
+    V: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, item) in self.data.iter().enumerate() {
+            item.fmt(f)?;
+            if (index + 1) % self.width == 0 {
+                f.write_char('\n')?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutation_by_coordinate() {
+        let mut grid = Grid::single_ascii_number("123\n456\n").unwrap();
+        grid[(2, 1)] = 0;
+        assert_eq!(grid.set(0, 0, 9), Some(1));
+        assert_eq!(grid.set(3, 0, 9), None);
+        assert_eq!(grid.to_string(), "923\n450\n");
+    }
+
+    #[test]
+    fn bounded_and_unbounded_neighbors() {
+        let grid = Grid::<u8>::new(2, 2);
+
+        let bounded: Vec<_> = grid.neighbors(0, 0, Direction::Cardinal).collect();
+        assert_eq!(bounded, [(0, 1), (1, 0)]);
+
+        let unbounded: Vec<_> = grid
+            .neighbors_unbounded(0, 0, Direction::Cardinal)
+            .collect();
+        assert_eq!(unbounded, [(0, 1), (0, -1), (-1, 0), (1, 0)]);
+        assert_eq!(
+            unbounded
+                .iter()
+                .filter(|&&(x, y)| grid.contains(x, y))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn rotations_and_flips() {
+        let grid = Grid::single_ascii_number("123\n456\n").unwrap();
+
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.rotate_90().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.rotate_180().to_string(), "654\n321\n");
+        assert_eq!(grid.rotate_270().to_string(), "36\n25\n14\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "321\n654\n");
+        assert_eq!(grid.flip_vertical().to_string(), "456\n123\n");
+        assert_eq!(grid.rotate_90().rotate_270(), grid);
+    }
+
+    #[test]
+    fn views_and_crops() {
+        let grid = Grid::single_ascii_number("123\n456\n789\n").unwrap();
+
+        let view = grid.view(1, 1, 2, 2).unwrap();
+        assert_eq!(
+            (view[(0, 0)], view.get(1, 1), view.get(2, 0)),
+            (5, Some(&9), None)
+        );
+        assert_eq!(grid.crop(0, 1, 3, 1).unwrap().to_string(), "456\n");
+        assert!(grid.view(2, 0, 2, 1).is_none());
+    }
+
+    #[test]
+    fn tiles_map_by_tile_position() {
+        let grid = Grid::single_ascii_number("12\n").unwrap();
+        let tiled = grid.tile(2, 2, |&v, tx, ty| v as usize + 10 * tx + 100 * ty);
+
+        assert_eq!((tiled.width(), tiled.height()), (4, 2));
+        assert_eq!(tiled.as_vec(), &[1, 2, 11, 12, 101, 102, 111, 112]);
+    }
+
+    #[test]
+    fn uneven_rows_are_reported() {
+        let error = Grid::single_ascii_number("123\n45\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "position is outside the grid")]
+    fn index_mut_checks_the_column() {
+        let mut grid: Grid<u8> = Grid::new(3, 2);
+        grid[(3, 0)] = 1;
+    }
+
+    #[test]
+    #[should_panic(expected = "position is outside the grid")]
+    fn index_checks_the_column() {
+        let grid: Grid<u8> = Grid::new(3, 2);
+        let _ = grid[(3, 0)];
+    }
+
+    #[test]
+    fn huge_views_do_not_fit() {
+        let grid: Grid<u8> = Grid::new(3, 2);
+        assert!(grid.view(1, 0, usize::MAX, 1).is_none());
+        assert!(grid.view(0, 1, 1, usize::MAX).is_none());
+        assert!(grid.crop(usize::MAX, 0, 2, 1).is_none());
+    }
+}
@@ -0,0 +1,104 @@
+//! Loading puzzle input at runtime instead of baking it into the binary.
+
+use std::{
+    fmt::Display,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+/// The path of the `input.txt` bundled next to the calling crate's `Cargo.toml`.
+#[macro_export]
+macro_rules! bundled_input {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")
+    };
+}
+
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// Standard input, selected with `-` on the command line.
+    Stdin,
+    /// A file on disk.
+    File(PathBuf),
+}
+
+impl Source {
+    /// Picks the source from a command line argument, falling back to `default`.
+    ///
+    /// `-` selects standard input, anything else is treated as a path.
+    pub fn from_arg(arg: Option<&str>, default: &str) -> Self {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(path.into()),
+            None => Source::File(default.into()),
+        }
+    }
+
+    /// Reads the whole input.
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Source::Stdin => {
+                let mut buffer = String::new();
+                io::stdin().read_to_string(&mut buffer).map(|_| buffer)
+            }
+            Source::File(path) => std::fs::read_to_string(path),
+        };
+
+        result.map_err(|error| InputError::Read {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => f.write_str("<stdin>"),
+            Source::File(path) => path.display().fmt(f),
+        }
+    }
+}
+
+/// Errors raised while locating or reading the puzzle input.
+#[derive(Debug)]
+pub enum InputError {
+    /// The command line didn't match `[INPUT | -]`.
+    Usage(String),
+    /// The input couldn't be read.
+    Read { source: Source, error: io::Error },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Usage(message) => f.write_str(message),
+            InputError::Read { source, error } => match error.kind() {
+                io::ErrorKind::NotFound => write!(f, "input file {} does not exist", source),
+                _ => write!(f, "could not read input from {}: {}", source, error),
+            },
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Usage(_) => None,
+            InputError::Read { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Picks the input named by the process arguments, or `default` if none was given.
+pub fn from_args(default: &str) -> Result<Source, InputError> {
+    let mut args = std::env::args();
+    let program = args.next().unwrap_or_default();
+    let arg = args.next();
+    if args.next().is_some() {
+        return Err(InputError::Usage(format!("usage: {} [INPUT | -]", program)));
+    }
+
+    Ok(Source::from_arg(arg.as_deref(), default))
+}
@@ -0,0 +1,10 @@
+//! Shared boilerplate for the Advent of Code 2021 solutions.
+
+pub mod bench;
+pub mod counter;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod path;
+pub mod runner;
+pub mod solution;
@@ -0,0 +1,190 @@
+//! Small helpers for turning puzzle input into values.
+//!
+//! Every helper reports failures as a [`ParseError`] that points at the
+//! offending text. Errors raised while parsing a slice of the input can be
+//! moved back into the coordinates of the whole input with [`ParseError::within`],
+//! which is what [`lines`] and [`separated`] do for every item.
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// An error raised while parsing puzzle input.
+///
+/// Lines and columns are 1-based and count characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    text: String,
+    line: usize,
+    column: usize,
+}
+
+impl ParseError {
+    /// Creates an error about `text`, located at its first character.
+    pub fn new(message: impl Into<String>, text: &str) -> Self {
+        Self {
+            message: message.into(),
+            text: text.to_owned(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    /// Rebases an error raised while parsing `part` so it's relative to `whole`.
+    ///
+    /// `part` must be a subslice of `whole`, otherwise the error is returned as is.
+    pub fn within(mut self, whole: &str, part: &str) -> Self {
+        let start = whole.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if offset > whole.len() {
+            return self;
+        }
+
+        let before = &whole[..offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count();
+
+        if self.line == 1 {
+            self.column += column;
+        }
+        self.line += before.matches('\n').count();
+        self
+    }
+
+    /// The description of what went wrong.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The offending text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The line the offending text starts on.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column the offending text starts on.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `s` with its [`FromStr`] implementation, e.g. a number.
+pub fn value<T>(s: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    s.parse()
+        .map_err(|error| ParseError::new(format!("invalid value ({})", error), s))
+}
+
+/// Splits `s` around the first `separator`, failing if there is none.
+pub fn split_once<'a>(s: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(separator)
+        .ok_or_else(|| ParseError::new(format!("expected `{}` in", separator), s))
+}
+
+/// Parses a line made of single decimal digits, e.g. `2199943210`.
+pub fn digits(line: &str) -> Result<Vec<u8>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| match c.to_digit(10) {
+            Some(digit) => Ok(digit as u8),
+            None => Err(unexpected(line, i, c, "a digit")),
+        })
+        .collect()
+}
+
+/// Checks that `line` only contains characters from `allowed`.
+///
+/// `expected` describes those characters in the error, e.g. `"a binary digit"`.
+pub fn only<'a>(line: &'a str, allowed: &str, expected: &str) -> Result<&'a str, ParseError> {
+    match line.char_indices().find(|&(_, c)| !allowed.contains(c)) {
+        Some((i, c)) => Err(unexpected(line, i, c, expected)),
+        None => Ok(line),
+    }
+}
+
+fn unexpected(line: &str, index: usize, c: char, expected: &str) -> ParseError {
+    let rest = &line[index..];
+    ParseError::new(format!("expected {}", expected), &rest[..c.len_utf8()]).within(line, rest)
+}
+
+/// Parses every line of the input with `f`.
+pub fn lines<'a, T, F>(input: &'a str, f: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&'a str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .map(|line| f(line).map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+/// Parses a list of values separated by `separator`, e.g. `3,4,3,1,2`.
+///
+/// Surrounding whitespace on each value is ignored.
+pub fn separated<'a, T, F>(input: &'a str, separator: char, f: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&'a str) -> Result<T, ParseError>,
+{
+    input
+        .trim()
+        .split(separator)
+        .map(|s| s.trim())
+        .map(|s| f(s).map_err(|e| e.within(input, s)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn within_rebases_columns_and_lines() {
+        let input = "1,2\n3,x4\n";
+        let line = input.lines().nth(1).unwrap();
+        let value = &line[2..];
+
+        let error = ParseError::new("bad", value).within(line, value);
+        assert_eq!((error.line(), error.column()), (1, 3));
+
+        let error = error.within(input, line);
+        assert_eq!((error.line(), error.column()), (2, 3));
+    }
+
+    #[test]
+    fn lines_reports_the_failing_line() {
+        let error = lines("12\n34\n5x\n", digits).unwrap_err();
+        assert_eq!((error.line(), error.column(), error.text()), (3, 2, "x"));
+    }
+
+    #[test]
+    fn separated_reports_the_failing_value() {
+        assert_eq!(separated("3, 4,5\n", ',', value::<u8>), Ok(vec![3, 4, 5]));
+
+        let error = separated("3,4,-1", ',', value::<u8>).unwrap_err();
+        assert_eq!((error.line(), error.column(), error.text()), (1, 5, "-1"));
+    }
+}
@@ -0,0 +1,532 @@
+//! Cheapest routes between two cells of a [`Grid`].
+
+use crate::grid::{Direction, Grid};
+use std::{
+    cmp::Ordering,
+    collections::BinaryHeap,
+    fmt::{self, Debug, Display, Write},
+};
+
+/// A numeric type path costs are added up in.
+///
+/// Implemented for the unsigned integers, so callers can pick the narrowest
+/// type their costs fit in.
+pub trait Cost: Copy + Ord + Debug {
+    const ZERO: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Converts a number of steps for the A* estimate, or [`None`] if it doesn't fit.
+    fn from_steps(steps: usize) -> Option<Self>;
+
+    /// Converts a cost to a bucket number for [`Algorithm::Dial`], or [`None`]
+    /// if it doesn't fit.
+    fn to_index(self) -> Option<usize>;
+}
+
+macro_rules! impl_cost {
+    ($($ty:ty),+) => {$(
+        impl Cost for $ty {
+            const ZERO: Self = 0;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$ty>::checked_add(self, other)
+            }
+
+            fn from_steps(steps: usize) -> Option<Self> {
+                steps.try_into().ok()
+            }
+
+            fn to_index(self) -> Option<usize> {
+                self.try_into().ok()
+            }
+        }
+    )+};
+}
+
+impl_cost!(u8, u16, u32, u64, u128, usize);
+
+/// Why [`Grid::shortest_path`] found no route.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathError {
+    /// Either end lies outside the grid.
+    OutOfBounds((usize, usize)),
+    /// No allowed steps lead from the start to the goal.
+    Unreachable,
+    /// Every route to the goal costs more than the cost type can hold.
+    Overflow { cost_type: &'static str },
+    /// A step cost more than the `max_step` given to [`Algorithm::Dial`].
+    StepTooLarge { max_step: usize },
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::OutOfBounds((x, y)) => write!(f, "({}, {}) is outside the grid", x, y),
+            PathError::Unreachable => f.write_str("the goal can't be reached"),
+            PathError::Overflow { cost_type } => {
+                write!(f, "the path cost doesn't fit in {}", cost_type)
+            }
+            PathError::StepTooLarge { max_step } => {
+                write!(f, "a step cost more than the maximum of {}", max_step)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+/// How [`Grid::shortest_path`] searches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    /// Expands cells in order of their cost from the start.
+    Dijkstra,
+    /// Adds the Manhattan distance to the goal as an estimate of the remaining
+    /// cost, so it explores fewer cells. The result is only the cheapest when
+    /// every step costs at least 1, as with day 15's risk levels.
+    AStar,
+    /// Dijkstra's algorithm with a circular bucket queue instead of a binary
+    /// heap (Dial's algorithm). Needs every step to cost at most `max_step`,
+    /// and is fastest when that's small, like the 1 to 9 of day 15.
+    Dial { max_step: usize },
+}
+
+/// A route through a grid along with its total cost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<C> {
+    pub cost: C,
+    /// Every position on the route, from the start to the goal inclusive.
+    pub positions: Vec<(usize, usize)>,
+}
+
+/// An entry in the search queue, ordered so the cheapest comes out of the heap first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Node<C> {
+    priority: C,
+    cost: C,
+    position: (usize, usize),
+}
+
+impl<C: Ord> PartialOrd for Node<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for Node<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// The cells waiting to be expanded, cheapest first.
+trait Frontier<C> {
+    fn push(&mut self, node: Node<C>) -> Result<(), PathError>;
+    fn pop(&mut self) -> Option<Node<C>>;
+}
+
+impl<C: Ord> Frontier<C> for BinaryHeap<Node<C>> {
+    fn push(&mut self, node: Node<C>) -> Result<(), PathError> {
+        BinaryHeap::push(self, node);
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<Node<C>> {
+        BinaryHeap::pop(self)
+    }
+}
+
+/// A bucket per priority, reused in a ring.
+///
+/// While the cheapest queued priority is `p`, nothing above `p + max_step` can
+/// be queued, so `max_step + 1` buckets are enough.
+struct Buckets<C> {
+    buckets: Vec<Vec<Node<C>>>,
+    /// The priority of the bucket being emptied.
+    current: usize,
+    len: usize,
+}
+
+impl<C> Buckets<C> {
+    fn new(max_step: usize) -> Self {
+        Self {
+            buckets: (0..=max_step).map(|_| Vec::new()).collect(),
+            current: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<C: Cost> Frontier<C> for Buckets<C> {
+    fn push(&mut self, node: Node<C>) -> Result<(), PathError> {
+        let priority = node.priority.to_index().ok_or(PathError::Overflow {
+            cost_type: std::any::type_name::<C>(),
+        })?;
+        if priority - self.current >= self.buckets.len() {
+            return Err(PathError::StepTooLarge {
+                max_step: self.buckets.len() - 1,
+            });
+        }
+
+        let count = self.buckets.len();
+        self.buckets[priority % count].push(node);
+        self.len += 1;
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<Node<C>> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let count = self.buckets.len();
+        loop {
+            if let Some(node) = self.buckets[self.current % count].pop() {
+                self.len -= 1;
+                return Some(node);
+            }
+            self.current += 1;
+        }
+    }
+}
+
+fn manhattan(a: (usize, usize), b: (usize, usize)) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+impl<V> Grid<V> {
+    /// Finds the cheapest route from `start` to `goal`, moving up, down, left
+    /// and right.
+    ///
+    /// `cost` gives the price of stepping from one position to its neighbour,
+    /// or [`None`] if the step isn't allowed. Costs are added with overflow
+    /// checks: routes that overflow `C` are dropped, and if that leaves no way
+    /// to the goal the result is [`PathError::Overflow`] rather than a wrong total.
+    pub fn shortest_path<C, F>(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        algorithm: Algorithm,
+        cost: F,
+    ) -> Result<Path<C>, PathError>
+    where
+        C: Cost,
+        F: Fn((usize, usize), (usize, usize)) -> Option<C>,
+    {
+        for end in [start, goal] {
+            self.get(end.0, end.1).ok_or(PathError::OutOfBounds(end))?;
+        }
+
+        match algorithm {
+            Algorithm::Dijkstra => {
+                let priority = |total, _| Some(total);
+                self.search(start, goal, BinaryHeap::new(), priority, cost)
+            }
+            Algorithm::AStar => {
+                let estimate = |total: C, position| {
+                    total.checked_add(C::from_steps(manhattan(position, goal))?)
+                };
+                self.search(start, goal, BinaryHeap::new(), estimate, cost)
+            }
+            Algorithm::Dial { max_step } => {
+                let priority = |total, _| Some(total);
+                self.search(start, goal, Buckets::new(max_step), priority, cost)
+            }
+        }
+    }
+
+    /// Best-first search from `start`, expanding cells in order of `priority`.
+    fn search<C, F, P, Q>(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        mut queue: Q,
+        priority: P,
+        cost: F,
+    ) -> Result<Path<C>, PathError>
+    where
+        C: Cost,
+        F: Fn((usize, usize), (usize, usize)) -> Option<C>,
+        P: Fn(C, (usize, usize)) -> Option<C>,
+        Q: Frontier<C>,
+    {
+        let index = |(x, y): (usize, usize)| y * self.width() + x;
+        let mut dist: Vec<Option<C>> = vec![None; self.width() * self.height()];
+        let mut previous = vec![None; dist.len()];
+        // cells a step into was dropped because its cost overflowed
+        let mut overflowed = Vec::new();
+        dist[index(start)] = Some(C::ZERO);
+        match priority(C::ZERO, start) {
+            Some(priority) => queue.push(Node {
+                priority,
+                cost: C::ZERO,
+                position: start,
+            })?,
+            None => overflowed.push(start),
+        }
+
+        while let Some(Node {
+            cost: current,
+            position,
+            ..
+        }) = queue.pop()
+        {
+            if position == goal {
+                let mut positions = vec![goal];
+                while let Some(before) = previous[index(positions[positions.len() - 1])] {
+                    positions.push(before);
+                }
+                positions.reverse();
+
+                return Ok(Path {
+                    cost: current,
+                    positions,
+                });
+            }
+            if dist[index(position)].is_some_and(|best| current > best) {
+                continue;
+            }
+
+            for next in self.neighbors(position.0, position.1, Direction::Cardinal) {
+                let Some(step) = cost(position, next) else {
+                    continue;
+                };
+                let Some((total, priority)) = current
+                    .checked_add(step)
+                    .and_then(|total| Some((total, priority(total, next)?)))
+                else {
+                    overflowed.push(next);
+                    continue;
+                };
+
+                if dist[index(next)].is_none_or(|best| total < best) {
+                    dist[index(next)] = Some(total);
+                    previous[index(next)] = Some(position);
+                    queue.push(Node {
+                        priority,
+                        cost: total,
+                        position: next,
+                    })?;
+                }
+            }
+        }
+
+        // Every cell reached with a finite cost has been expanded, so if the
+        // goal can be reached from a dropped step at all, it's only by a route
+        // that costs too much.
+        if self.connects(overflowed, goal, &cost) {
+            Err(PathError::Overflow {
+                cost_type: std::any::type_name::<C>(),
+            })
+        } else {
+            Err(PathError::Unreachable)
+        }
+    }
+
+    /// Whether `goal` can be reached from any of `starts` by allowed steps,
+    /// whatever they cost.
+    fn connects<C, F>(&self, starts: Vec<(usize, usize)>, goal: (usize, usize), cost: &F) -> bool
+    where
+        F: Fn((usize, usize), (usize, usize)) -> Option<C>,
+    {
+        let mut seen = Grid::<bool>::new(self.width(), self.height());
+        for &start in &starts {
+            seen[start] = true;
+        }
+
+        let mut stack = starts;
+        while let Some(position) = stack.pop() {
+            if position == goal {
+                return true;
+            }
+            for next in self.neighbors(position.0, position.1, Direction::Cardinal) {
+                if !seen[next] && cost(position, next).is_some() {
+                    seen[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        false
+    }
+
+    /// Draws the grid with only the cells on `path` showing their values.
+    pub fn with_path<'a, C>(&'a self, path: &'a Path<C>) -> PathOverlay<'a, V, C> {
+        PathOverlay { grid: self, path }
+    }
+}
+
+/// Renders a [`Path`] over a grid, see [`Grid::with_path`].
+///
+/// Cells off the path are drawn as dots, as many as their value is wide.
+pub struct PathOverlay<'a, V, C> {
+    grid: &'a Grid<V>,
+    path: &'a Path<C>,
+}
+
+impl<V, C> Display for PathOverlay<'_, V, C>
+where
+    V: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut on_path = Grid::new(self.grid.width(), self.grid.height());
+        for &position in &self.path.positions {
+            on_path[position] = true;
+        }
+
+        for (position, value) in self.grid.items() {
+            if on_path[position] {
+                write!(f, "{}", value)?;
+            } else {
+                let width = value.to_string().chars().count();
+                write!(f, "{}", ".".repeat(width))?;
+            }
+            if position.0 + 1 == self.grid.width() {
+                f.write_char('\n')?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CAVE: &str = "\
+116
+138
+213
+";
+
+    fn risk<C: From<u8>>(
+        grid: &Grid<u8>,
+    ) -> impl Fn((usize, usize), (usize, usize)) -> Option<C> + '_ {
+        |_, to| Some(grid[to].into())
+    }
+
+    #[test]
+    fn both_algorithms_find_the_cheapest_route() {
+        let grid = Grid::single_ascii_number(CAVE).unwrap();
+
+        for algorithm in [
+            Algorithm::Dijkstra,
+            Algorithm::AStar,
+            Algorithm::Dial { max_step: 9 },
+        ] {
+            let path = grid
+                .shortest_path((0, 0), (2, 2), algorithm, risk::<u32>(&grid))
+                .unwrap();
+            assert_eq!(path.cost, 7);
+            assert_eq!(path.positions, [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]);
+            assert_eq!(grid.with_path(&path).to_string(), "1..\n1..\n213\n");
+        }
+    }
+
+    #[test]
+    fn blocked_steps_and_bad_ends() {
+        let grid = Grid::single_ascii_number(CAVE).unwrap();
+        let avoid_8 = |_, to: (usize, usize)| (grid[to] != 8).then_some(grid[to] as u32);
+
+        let path = grid
+            .shortest_path((2, 0), (2, 2), Algorithm::AStar, avoid_8)
+            .unwrap();
+        assert_eq!(path.positions, [(2, 0), (1, 0), (1, 1), (1, 2), (2, 2)]);
+
+        let walls = |_, _| None::<u32>;
+        assert_eq!(
+            grid.shortest_path((0, 0), (2, 2), Algorithm::Dijkstra, walls),
+            Err(PathError::Unreachable)
+        );
+        assert_eq!(
+            grid.shortest_path((0, 0), (3, 0), Algorithm::Dijkstra, risk::<u32>(&grid)),
+            Err(PathError::OutOfBounds((3, 0)))
+        );
+
+        let here = grid
+            .shortest_path((1, 1), (1, 1), Algorithm::Dijkstra, risk::<u32>(&grid))
+            .unwrap();
+        assert_eq!((here.cost, here.positions), (0, vec![(1, 1)]));
+    }
+
+    #[test]
+    fn overflowing_routes_are_reported() {
+        let grid = Grid::<u8>::new(2, 2);
+        let steep = |_, _| Some(200u8);
+
+        let path = grid
+            .shortest_path((0, 0), (1, 0), Algorithm::AStar, steep)
+            .unwrap();
+        assert_eq!(path.cost, 200);
+
+        assert_eq!(
+            grid.shortest_path((0, 0), (1, 1), Algorithm::Dijkstra, steep),
+            Err(PathError::Overflow { cost_type: "u8" })
+        );
+        let wide = |from, to| steep(from, to).map(u16::from);
+        assert_eq!(
+            grid.shortest_path((0, 0), (1, 1), Algorithm::Dijkstra, wide)
+                .map(|path| path.cost),
+            Ok(400)
+        );
+    }
+
+    #[test]
+    fn walled_off_goals_are_unreachable_despite_overflow() {
+        // Stepping into (1, 0) always overflows a u8, and its neighbour (2, 0)
+        // is the goal, walled off unless `open`.
+        let grid = Grid::<u8>::new(3, 2);
+        let costs = |open: bool| {
+            move |_, to| match to {
+                (2, 0) if !open => None,
+                (1, 0) => Some(255u8),
+                _ => Some(1),
+            }
+        };
+
+        let algorithms = [
+            Algorithm::Dijkstra,
+            Algorithm::AStar,
+            Algorithm::Dial { max_step: 255 },
+        ];
+        for algorithm in algorithms {
+            assert_eq!(
+                grid.shortest_path((0, 1), (2, 0), algorithm, costs(false)),
+                Err(PathError::Unreachable),
+                "{:?}",
+                algorithm
+            );
+            assert_eq!(
+                grid.shortest_path((0, 1), (2, 0), algorithm, costs(true))
+                    .map(|path| path.cost),
+                Ok(3),
+                "{:?}",
+                algorithm
+            );
+        }
+
+        let dead_end = |_, to| match to {
+            (2, 0) | (2, 1) => None,
+            (1, 0) => Some(255u8),
+            _ => Some(1),
+        };
+        assert_eq!(
+            grid.shortest_path((0, 1), (1, 0), Algorithm::Dijkstra, dead_end),
+            Err(PathError::Overflow { cost_type: "u8" })
+        );
+    }
+
+    #[test]
+    fn dial_rejects_steps_above_its_maximum() {
+        let grid = Grid::single_ascii_number(CAVE).unwrap();
+        let dial = Algorithm::Dial { max_step: 3 };
+
+        assert_eq!(
+            grid.shortest_path((0, 0), (2, 2), dial, risk::<u32>(&grid)),
+            Err(PathError::StepTooLarge { max_step: 3 })
+        );
+
+        let free = |_, _| Some(0u32);
+        let path = grid.shortest_path((0, 0), (2, 2), dial, free).unwrap();
+        assert_eq!((path.cost, path.positions.len()), (0, 5));
+    }
+}
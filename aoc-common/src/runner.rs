@@ -0,0 +1,76 @@
+//! Helpers shared by every day's `main` and the `aoc` runner.
+
+use crate::{
+    input,
+    solution::{Answer, Solution},
+};
+use std::{fmt::Display, time::Duration};
+
+/// Entry point for a single day's binary.
+///
+/// Reads the input given on the command line, or the bundled one if there is
+/// none. Prints the error and exits if the input can't be read or parsed.
+pub fn main<S: Solution>() {
+    let source = input::from_args(S::INPUT).unwrap_or_else(|error| fail(error));
+    let input = source.read().unwrap_or_else(|error| fail(error));
+    let input = S::parse(&input).unwrap_or_else(|error| fail(format!("{}: {}", source, error)));
+
+    print_answers(S::part1(&input), S::part2(&input));
+}
+
+/// Prints `error` and exits with a failure status.
+pub fn fail(error: impl Display) -> ! {
+    eprintln!("error: {}", error);
+    std::process::exit(1)
+}
+
+/// Prints both answers in the usual `PART N: ...` format.
+///
+/// Answers spanning multiple lines (e.g. ASCII art) start on their own line.
+pub fn print_answers(part1: impl Display, part2: impl Display) {
+    print_answer(1, part1);
+    print_answer(2, part2);
+}
+
+fn print_answer(part: u8, answer: impl Display) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("PART {}:\n{}", part, answer);
+    } else {
+        println!("PART {}: {}", part, answer);
+    }
+}
+
+/// Formats a duration with a unit suited to its magnitude, e.g. `12.3ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+/// Prints answers from several days as an aligned table.
+///
+/// Multi-line answers continue on the following lines, aligned to the answer column.
+pub fn print_table<'a>(rows: impl IntoIterator<Item = (&'a str, &'a Answer)>) {
+    println!("{:<5} {:<4} {:>10}  ANSWER", "DAY", "PART", "TIME");
+    for (name, answer) in rows {
+        let mut lines = answer.value.lines();
+        println!(
+            "{:<5} {:<4} {:>10}  {}",
+            name,
+            answer.part,
+            format_duration(answer.elapsed),
+            lines.next().unwrap_or_default()
+        );
+        for line in lines {
+            println!("{:<23}{}", "", line);
+        }
+    }
+}
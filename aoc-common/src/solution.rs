@@ -0,0 +1,155 @@
+//! The interface every day implements, plus a type-erased wrapper for runners.
+
+use crate::{
+    bench::{self, Stage, Timing},
+    parse::ParseError,
+};
+use std::{
+    fmt::Display,
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+/// A puzzle solution: a parse step followed by two independent parts.
+pub trait Solution {
+    /// The day of the puzzle, e.g. `15`.
+    const DAY: u8;
+    /// Path to the input bundled with the crate, usually [`bundled_input!`](crate::bundled_input).
+    const INPUT: &'static str;
+
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Parses `1` or `2`.
+    pub fn from_number(s: &str) -> Option<Self> {
+        match s {
+            "1" => Some(Part::One),
+            "2" => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// A computed answer along with how long the part took.
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub elapsed: Duration,
+}
+
+/// An object-safe view over a [`Solution`], so runners can keep a list of days.
+pub trait Puzzle {
+    /// The day of the puzzle.
+    fn day(&self) -> u8;
+
+    /// Path to the bundled input.
+    fn input_path(&self) -> &'static str;
+
+    /// Parses `input` and runs the requested parts, both if `part` is [`None`].
+    fn solve(&self, input: &str, part: Option<Part>) -> Result<Vec<Answer>, ParseError>;
+
+    /// Times parsing `input` and then each requested part, `iterations` times apiece.
+    fn bench(
+        &self,
+        input: &str,
+        part: Option<Part>,
+        iterations: usize,
+    ) -> Result<Vec<Timing>, ParseError>;
+}
+
+struct Erased<S>(PhantomData<S>);
+
+impl<S> Puzzle for Erased<S>
+where
+    S: Solution,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn input_path(&self) -> &'static str {
+        S::INPUT
+    }
+
+    fn solve(&self, input: &str, part: Option<Part>) -> Result<Vec<Answer>, ParseError> {
+        let parsed = S::parse(input)?;
+        let answers = Part::ALL
+            .into_iter()
+            .filter(|&p| part.is_none_or(|part| part == p))
+            .map(|part| {
+                let start = Instant::now();
+                let value = match part {
+                    Part::One => S::part1(&parsed).to_string(),
+                    Part::Two => S::part2(&parsed).to_string(),
+                };
+                Answer {
+                    part,
+                    value,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect();
+        Ok(answers)
+    }
+
+    fn bench(
+        &self,
+        input: &str,
+        part: Option<Part>,
+        iterations: usize,
+    ) -> Result<Vec<Timing>, ParseError> {
+        let parsed = S::parse(input)?;
+        let mut timings = vec![Timing {
+            stage: Stage::Parse,
+            samples: bench::measure(iterations, || S::parse(input)),
+        }];
+        timings.extend(
+            Part::ALL
+                .into_iter()
+                .filter(|&p| part.is_none_or(|part| part == p))
+                .map(|part| Timing {
+                    stage: Stage::Part(part),
+                    samples: match part {
+                        Part::One => bench::measure(iterations, || S::part1(&parsed)),
+                        Part::Two => bench::measure(iterations, || S::part2(&parsed)),
+                    },
+                }),
+        );
+        Ok(timings)
+    }
+}
+
+/// Type-erases a [`Solution`].
+pub fn puzzle<S>() -> Box<dyn Puzzle>
+where
+    S: Solution + 'static,
+{
+    Box::new(Erased::<S>(PhantomData))
+}
@@ -0,0 +1,142 @@
+//! The manifest of accepted answers, `answers.txt` at the workspace root.
+//!
+//! Every line that isn't blank or a `#` comment reads `<day> <part> <answer>`,
+//! where the day is an entry name such as `15` or `2v2` and the answer is the
+//! rest of the line. Newlines inside multi-line answers are written as `\n`.
+
+use aoc_common::{
+    parse::{self, ParseError},
+    solution::{Answer, Part},
+};
+use std::fmt::{self, Display};
+
+/// Path to the checked-in manifest.
+pub const PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt");
+
+/// An accepted answer for one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub name: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+/// Parses the manifest format described in the module documentation.
+pub fn parse(input: &str) -> Result<Vec<Expected>, ParseError> {
+    let lines = parse::lines(input, |line| {
+        if line.trim().is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+
+        let (name, rest) = parse::split_once(line, " ")?;
+        let (part, answer) = parse::split_once(rest, " ").map_err(|e| e.within(line, rest))?;
+        let part = Part::from_number(part)
+            .ok_or_else(|| ParseError::new("expected part 1 or 2", part).within(line, part))?;
+
+        Ok(Some(Expected {
+            name: name.to_owned(),
+            part,
+            answer: unescape(answer),
+        }))
+    })?;
+
+    Ok(lines.into_iter().flatten().collect())
+}
+
+/// Reads and parses the checked-in manifest.
+pub fn load() -> Result<Vec<Expected>, String> {
+    let manifest = std::fs::read_to_string(PATH).map_err(|e| format!("{}: {}", PATH, e))?;
+    parse(&manifest).map_err(|e| format!("{}: {}", PATH, e))
+}
+
+/// The outcome of comparing a computed answer with the manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict<'a> {
+    Pass,
+    Fail { expected: &'a str },
+    Missing,
+}
+
+impl Verdict<'_> {
+    /// Looks up the accepted answer for `name`'s part and compares it with `answer`.
+    pub fn of<'a>(expected: &'a [Expected], name: &str, answer: &Answer) -> Verdict<'a> {
+        match expected
+            .iter()
+            .find(|e| e.name == name && e.part == answer.part)
+        {
+            Some(e) if e.answer == answer.value => Verdict::Pass,
+            Some(e) => Verdict::Fail {
+                expected: &e.answer,
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+impl Display for Verdict<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail { .. } => "FAIL",
+            Verdict::Missing => "missing",
+        })
+    }
+}
+
+/// Escapes an answer so it fits on a single manifest line.
+pub fn escape(answer: &str) -> String {
+    answer.replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    answer.replace("\\n", "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_entries_and_skips_comments() {
+        let manifest = "# comment\n\n2v2 1 150\n13 2 XX\\nXX\\n\n";
+        let expected = parse(manifest).unwrap();
+        assert_eq!(expected.len(), 2);
+        assert_eq!(expected[0].name, "2v2");
+        assert_eq!(expected[1].part, Part::Two);
+        assert_eq!(expected[1].answer, "XX\nXX\n");
+        assert_eq!(escape(&expected[1].answer), "XX\\nXX\\n");
+    }
+
+    #[test]
+    fn verdicts_compare_by_name_and_part() {
+        let expected = parse("1 1 42\n2v2 1 7\n").unwrap();
+        let answer = |part, value: &str| Answer {
+            part,
+            value: value.to_owned(),
+            elapsed: Default::default(),
+        };
+
+        assert_eq!(
+            Verdict::of(&expected, "1", &answer(Part::One, "42")),
+            Verdict::Pass
+        );
+        assert_eq!(
+            Verdict::of(&expected, "2v2", &answer(Part::One, "8")),
+            Verdict::Fail { expected: "7" }
+        );
+        assert_eq!(
+            Verdict::of(&expected, "2", &answer(Part::One, "7")),
+            Verdict::Missing
+        );
+        assert_eq!(
+            Verdict::of(&expected, "1", &answer(Part::Two, "42")),
+            Verdict::Missing
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_parts() {
+        let error = parse("1 3 42\n").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.text()), (1, 3, "3"));
+    }
+}
@@ -0,0 +1,80 @@
+//! The list of every solved day, shared by the `aoc` binary and its tests.
+
+pub mod answers;
+
+use aoc_common::solution::{puzzle, Puzzle};
+
+/// A day's solution along with the name it's selected by on the command line.
+pub struct Entry {
+    pub name: &'static str,
+    pub puzzle: Box<dyn Puzzle>,
+}
+
+macro_rules! entries {
+    ($($name:literal => $solution:ty),+ $(,)?) => {
+        vec![$(Entry {
+            name: $name,
+            puzzle: puzzle::<$solution>(),
+        }),+]
+    };
+}
+
+/// Every registered day, in puzzle order.
+pub fn entries() -> Vec<Entry> {
+    entries![
+        "1" => day_1::Day1,
+        "2" => day_2::Day2,
+        "2v2" => day_2v2::Day2,
+        "3" => day_3::Day3,
+        "4" => day_4::Day4,
+        "5" => day_5::Day5,
+        "6" => day_6::Day6,
+        "7" => day_7::Day7,
+        "8" => day_8::Day8,
+        "9" => day_9::Day9,
+        "10" => day_10::Day10,
+        "11" => day_11::Day11,
+        "12" => day_12::Day12,
+        "13" => day_13::Day13,
+        "14" => day_14::Day14,
+        "15" => day_15::Day15,
+        "20" => day_20::Day20,
+        "21" => day_21::Day21,
+    ]
+}
+
+/// The entries `selector` picks: `all`, the entry with that exact name (so `2`
+/// is just day 2 and `2v2` its variant), or else every entry for that day number.
+pub fn select(selector: &str) -> Vec<Entry> {
+    let mut entries = entries();
+    if selector == "all" {
+        return entries;
+    }
+    if let Some(index) = entries.iter().position(|entry| entry.name == selector) {
+        return vec![entries.swap_remove(index)];
+    }
+
+    let day = selector.parse::<u8>().ok();
+    entries
+        .into_iter()
+        .filter(|entry| Some(entry.puzzle.day()) == day)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(selector: &str) -> Vec<&'static str> {
+        select(selector).iter().map(|entry| entry.name).collect()
+    }
+
+    #[test]
+    fn exact_names_win_over_day_numbers() {
+        assert_eq!(names("2"), ["2"]);
+        assert_eq!(names("2v2"), ["2v2"]);
+        assert_eq!(names("02"), ["2", "2v2"]);
+        assert_eq!(names("16"), Vec::<&str>::new());
+        assert_eq!(names("all").len(), entries().len());
+    }
+}
@@ -0,0 +1,229 @@
+use aoc::{
+    answers::{self, Expected, Verdict},
+    Entry,
+};
+use aoc_common::{
+    bench::{self, Format},
+    input::Source,
+    parse::ParseError,
+    runner,
+    solution::{Answer, Part},
+};
+
+const USAGE: &str = "usage: aoc <DAY | all> [--part 1|2] [--input PATH | -]
+       aoc verify [DAY | all] [--part 1|2]
+       aoc bench [DAY | all] [--part 1|2] [--input PATH | -]
+                 [--iterations N] [--format table|csv|json]";
+
+/// What to do with the selected days.
+enum Mode {
+    Solve,
+    Verify,
+    Bench { iterations: usize, format: Format },
+}
+
+struct Options {
+    mode: Mode,
+    selector: String,
+    part: Option<Part>,
+    input: Option<String>,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut mode = Mode::Solve;
+        let mut selector = None;
+        let mut part = None;
+        let mut input = None;
+        let mut iterations = None;
+        let mut format = None;
+
+        while let Some(arg) = args.next() {
+            let first = matches!(mode, Mode::Solve) && selector.is_none();
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    let value = args.next().ok_or("--part needs a value")?;
+                    part = Some(
+                        Part::from_number(&value)
+                            .ok_or_else(|| format!("invalid part: {}", value))?,
+                    );
+                }
+                "--input" | "-i" => input = Some(args.next().ok_or("--input needs a value")?),
+                "--iterations" | "-n" => {
+                    let value = args.next().ok_or("--iterations needs a value")?;
+                    iterations = Some(
+                        value
+                            .parse::<usize>()
+                            .ok()
+                            .filter(|&n| n > 0)
+                            .ok_or_else(|| format!("invalid iteration count: {}", value))?,
+                    );
+                }
+                "--format" | "-f" => {
+                    let value = args.next().ok_or("--format needs a value")?;
+                    format = Some(
+                        Format::from_name(&value)
+                            .ok_or_else(|| format!("invalid format: {}", value))?,
+                    );
+                }
+                "--help" | "-h" => return Err(USAGE.to_owned()),
+                "verify" if first => mode = Mode::Verify,
+                "bench" if first => {
+                    mode = Mode::Bench {
+                        iterations: 0,
+                        format: Format::Table,
+                    }
+                }
+                _ if selector.is_none() => selector = Some(arg),
+                _ => return Err(format!("unexpected argument: {}", arg)),
+            }
+        }
+
+        match &mut mode {
+            Mode::Verify if input.is_some() => {
+                return Err("verify only checks the bundled inputs".to_owned())
+            }
+            Mode::Bench {
+                iterations: n,
+                format: f,
+            } => {
+                *n = iterations.unwrap_or(10);
+                *f = format.unwrap_or(Format::Table);
+            }
+            _ if iterations.is_some() || format.is_some() => {
+                return Err("--iterations and --format only apply to bench".to_owned())
+            }
+            _ => {}
+        }
+        let selector = match selector {
+            None if !matches!(mode, Mode::Solve) => "all".to_owned(),
+            selector => selector.ok_or(USAGE)?,
+        };
+
+        Ok(Self {
+            mode,
+            selector,
+            part,
+            input,
+        })
+    }
+}
+
+fn main() {
+    let options = Options::parse(std::env::args().skip(1)).unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        std::process::exit(2)
+    });
+    let expected = if let Mode::Verify = options.mode {
+        answers::load().unwrap_or_else(|message| {
+            eprintln!("error: {}", message);
+            std::process::exit(2)
+        })
+    } else {
+        Vec::new()
+    };
+
+    let entries: Vec<Entry> = aoc::select(&options.selector);
+
+    if entries.is_empty() {
+        eprintln!("error: no day matches {}", options.selector);
+        std::process::exit(2);
+    }
+    if options.input.is_some() && entries.len() > 1 {
+        eprintln!("error: --input needs a single day");
+        std::process::exit(2);
+    }
+
+    let failed = match options.mode {
+        Mode::Solve => {
+            let (results, failed) = run(&entries, &options, |entry, input| {
+                entry.puzzle.solve(input, options.part)
+            });
+            if !results.is_empty() {
+                runner::print_table(results.iter().map(|(name, answer)| (*name, answer)));
+            }
+            failed
+        }
+        Mode::Verify => {
+            let (results, failed) = run(&entries, &options, |entry, input| {
+                entry.puzzle.solve(input, options.part)
+            });
+            failed | !print_verdicts(&expected, &results)
+        }
+        Mode::Bench { iterations, format } => {
+            let (results, failed) = run(&entries, &options, |entry, input| {
+                entry.puzzle.bench(input, options.part, iterations)
+            });
+            let rows = results.iter().map(|(name, timing)| (*name, timing));
+            bench::write_report(&mut std::io::stdout().lock(), format, rows)
+                .unwrap_or_else(|error| eprintln!("error: {}", error));
+            failed
+        }
+    };
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+/// Reads each entry's input and hands it to `f`, printing any errors.
+///
+/// Returns the collected rows and whether any day failed.
+fn run<'a, T>(
+    entries: &'a [Entry],
+    options: &Options,
+    f: impl Fn(&Entry, &str) -> Result<Vec<T>, ParseError>,
+) -> (Vec<(&'a str, T)>, bool) {
+    let mut failed = false;
+    let mut results = Vec::new();
+    for entry in entries {
+        let source = Source::from_arg(options.input.as_deref(), entry.puzzle.input_path());
+        let rows = source
+            .read()
+            .map_err(|e| e.to_string())
+            .and_then(|input| f(entry, &input).map_err(|e| format!("{}: {}", source, e)));
+
+        match rows {
+            Ok(rows) => results.extend(rows.into_iter().map(|row| (entry.name, row))),
+            Err(error) => {
+                eprintln!("error: day {}: {}", entry.name, error);
+                failed = true;
+            }
+        }
+    }
+    (results, failed)
+}
+
+/// Prints how each answer compares with the manifest, followed by a summary.
+///
+/// Returns whether every recorded answer matched.
+fn print_verdicts(expected: &[Expected], results: &[(&str, Answer)]) -> bool {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    println!("{:<5} {:<4} {:>10}  RESULT", "DAY", "PART", "TIME");
+    for (name, answer) in results {
+        let verdict = Verdict::of(expected, name, answer);
+        println!(
+            "{:<5} {:<4} {:>10}  {}",
+            name,
+            answer.part,
+            runner::format_duration(answer.elapsed),
+            verdict
+        );
+
+        match verdict {
+            Verdict::Pass => passed += 1,
+            Verdict::Fail { expected } => {
+                println!("{:<23}expected {}", "", answers::escape(expected));
+                println!("{:<23}     got {}", "", answers::escape(&answer.value));
+                failed += 1;
+            }
+            Verdict::Missing => missing += 1,
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+    failed == 0
+}
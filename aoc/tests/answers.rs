@@ -0,0 +1,49 @@
+//! Runs every day against its bundled input and compares with `answers.txt`.
+
+use aoc::answers::{self, Verdict};
+
+#[test]
+fn bundled_inputs_match_recorded_answers() {
+    let manifest = std::fs::read_to_string(answers::PATH).expect("answers.txt is readable");
+    let expected = answers::parse(&manifest).expect("answers.txt is well formed");
+    let entries = aoc::entries();
+
+    let mut mismatches = Vec::new();
+    for entry in &entries {
+        let recorded: Vec<_> = expected.iter().filter(|e| e.name == entry.name).collect();
+        if recorded.is_empty() {
+            continue;
+        }
+
+        let input = std::fs::read_to_string(entry.puzzle.input_path()).expect("bundled input");
+        let answers = entry
+            .puzzle
+            .solve(&input, None)
+            .expect("bundled input parses");
+        for answer in &answers {
+            if let Verdict::Fail { expected } = Verdict::of(&expected, entry.name, answer) {
+                mismatches.push(format!(
+                    "day {} part {}: expected {:?}, got {:?}",
+                    entry.name, answer.part, expected, answer.value
+                ));
+            }
+        }
+    }
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+#[test]
+fn recorded_answers_name_registered_days() {
+    let manifest = std::fs::read_to_string(answers::PATH).expect("answers.txt is readable");
+    let expected = answers::parse(&manifest).expect("answers.txt is well formed");
+    let entries = aoc::entries();
+
+    for recorded in expected {
+        assert!(
+            entries.iter().any(|e| e.name == recorded.name),
+            "unknown day {} in answers.txt",
+            recorded.name
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+fn solve_part1(input: &[i32]) -> i32 {
+    input
+        .iter()
+        .fold((0, i32::MAX), |(sum, prev), n| {
+            (if n > &prev { sum + 1 } else { sum }, *n)
+        })
+        .0
+}
+
+fn solve_part2(input: &[i32]) -> i32 {
+    input
+        .windows(3)
+        .map(|n| n.iter().sum())
+        .fold((0, i32::MAX), |(sum, prev), n| {
+            (if n > prev { sum + 1 } else { sum }, n)
+        })
+        .0
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input, parse::value)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&input), 7);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part2(&input), 5);
+    }
+}
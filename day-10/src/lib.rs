@@ -0,0 +1,145 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+fn score_1(c: char) -> usize {
+    match c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => unreachable!(),
+    }
+}
+
+fn score_2(c: &char) -> usize {
+    match c {
+        '(' => 1,
+        '[' => 2,
+        '{' => 3,
+        '<' => 4,
+        _ => 0,
+    }
+}
+
+fn braces_match(open: char, close: char) -> bool {
+    matches!(
+        (open, close),
+        ('(', ')') | ('<', '>') | ('[', ']') | ('{', '}')
+    )
+}
+
+fn illegal_score(l: &str) -> usize {
+    let mut stack = vec![];
+    for ch in l.chars() {
+        match ch {
+            '(' | '[' | '{' | '<' => stack.push(ch),
+            ')' | ']' | '}' | '>' => {
+                if let Some(top) = stack.pop() {
+                    if !braces_match(top, ch) {
+                        return score_1(ch);
+                    }
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+    0
+}
+
+fn solve_part1(input: &str) -> usize {
+    input.lines().map(illegal_score).sum()
+}
+
+fn sum_incomplete_scores(s: Vec<char>) -> usize {
+    s.iter().rev().fold(0, |acc, ch| 5 * acc + score_2(ch))
+}
+
+fn fix_score(line: &str) -> Option<Vec<char>> {
+    let mut stack = vec![];
+    for ch in line.chars() {
+        match ch {
+            '(' | '[' | '{' | '<' => stack.push(ch),
+            ')' | ']' | '}' | '>' => {
+                if let Some(top) = stack.pop() {
+                    if !braces_match(top, ch) {
+                        return None;
+                    }
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+    Some(stack)
+}
+fn solve_part2(input: &str) -> usize {
+    let mut scores: Vec<usize> = input
+        .lines()
+        .filter_map(fix_score)
+        .map(sum_incomplete_scores)
+        .collect();
+
+    scores.sort();
+    scores[scores.len() / 2]
+}
+
+fn prepare_input(input: &str) -> Result<&str, ParseError> {
+    let input = input.trim_end();
+    parse::lines(input, |line| parse::only(line, "()[]{}<>", "a bracket"))?;
+    Ok(input)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        prepare_input(input).map(String::from)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input), 26397);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&input), 288957);
+    }
+}
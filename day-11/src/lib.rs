@@ -0,0 +1,125 @@
+use aoc_common::{
+    bundled_input,
+    grid::{Direction, Grid},
+    parse::ParseError,
+    solution::Solution,
+};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct Octopuses {
+    energy: Grid<u8>,
+    flashes: usize,
+}
+
+impl FromStr for Octopuses {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            energy: Grid::single_ascii_number(input)?,
+            flashes: 0,
+        })
+    }
+}
+
+impl Octopuses {
+    /// Raises every energy level, letting flashes spread, and returns whether
+    /// every octopus flashed.
+    fn step(&mut self) -> bool {
+        let mut flashed = Grid::new(self.energy.width(), self.energy.height());
+        let mut pending: Vec<_> = self.energy.coordinates().collect();
+        let mut count = 0;
+
+        while let Some((x, y)) = pending.pop() {
+            if flashed[(x, y)] {
+                continue;
+            }
+
+            self.energy[(x, y)] += 1;
+            if self.energy[(x, y)] > 9 {
+                self.energy[(x, y)] = 0;
+                flashed[(x, y)] = true;
+                count += 1;
+                pending.extend(self.energy.neighbors(x, y, Direction::Adjacent));
+            }
+        }
+        self.flashes += count;
+
+        count == self.energy.as_vec().len()
+    }
+}
+
+fn solve_part1(mut grid: Octopuses, steps: i32) -> usize {
+    for _ in 0..steps {
+        grid.step();
+    }
+    grid.flashes
+}
+
+fn solve_part2(mut grid: Octopuses) -> i32 {
+    let mut steps = 1;
+    loop {
+        if grid.step() {
+            return steps;
+        }
+        steps += 1;
+    }
+}
+
+fn prepare_input(input: &str) -> Result<Octopuses, ParseError> {
+    input.parse()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Octopuses;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input.clone(), 100)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input), 1656);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&input), 195);
+    }
+}
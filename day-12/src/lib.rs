@@ -0,0 +1,625 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct Graph {
+    inner: HashMap<String, Vec<String>>,
+}
+
+#[inline]
+fn is_small(a: &str) -> bool {
+    a.chars().all(|x| x.is_ascii_lowercase())
+}
+
+/// The rules a path through the caves follows.
+///
+/// Big caves can be visited any number of times and small ones once, except
+/// that up to `revisits` small caves can be visited up to `max_visits` times
+/// each. Paths never return to `start` and stop as soon as they reach `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Policy<'a> {
+    pub start: &'a str,
+    pub end: &'a str,
+    /// The most times a revisited small cave can be visited.
+    pub max_visits: u32,
+    /// How many small caves can be visited more than once.
+    pub revisits: u32,
+    /// Caves no path can go through.
+    pub forbidden: &'a [&'a str],
+}
+
+impl Policy<'static> {
+    /// Part 1: small caves are visited at most once.
+    pub const SINGLE_VISIT: Self = Self {
+        start: "start",
+        end: "end",
+        max_visits: 1,
+        revisits: 0,
+        forbidden: &[],
+    };
+    /// Part 2: a single small cave can be visited twice.
+    pub const ONE_REVISIT: Self = Self {
+        max_visits: 2,
+        revisits: 1,
+        ..Self::SINGLE_VISIT
+    };
+}
+
+impl Policy<'_> {
+    /// Whether a path can move into `cave` at all.
+    fn allows(&self, cave: &str) -> bool {
+        cave != self.start && !self.forbidden.contains(&cave)
+    }
+
+    /// Whether a path can enter a small cave it has visited `visits` times
+    /// already, when it has visited `revisited` small caves more than once.
+    ///
+    /// Returns the new number of revisited caves if it can.
+    fn enter_small(&self, visits: u32, revisited: u32) -> Option<u32> {
+        match visits {
+            0 => Some(revisited),
+            _ if visits >= self.max_visits => None,
+            1 if revisited < self.revisits => Some(revisited + 1),
+            1 => None,
+            _ => Some(revisited),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Traversal<'a> {
+    /// The caves visited so far, in order.
+    path: Vec<&'a str>,
+    /// How many small caves in `path` show up more than once.
+    revisited: u32,
+}
+
+impl<'a> Traversal<'a> {
+    fn new(node: &'a str) -> Self {
+        Self {
+            path: vec![node],
+            revisited: 0,
+        }
+    }
+
+    fn node(&self) -> &'a str {
+        self.path[self.path.len() - 1]
+    }
+
+    fn augment(&self, node: &'a str, revisited: u32) -> Self {
+        let mut path = Vec::with_capacity(self.path.len() + 1);
+        path.extend_from_slice(&self.path);
+        path.push(node);
+        Self { path, revisited }
+    }
+}
+
+/// A path from `start` to `end`, as the caves it visits in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<'a>(Vec<&'a str>);
+
+impl<'a> Path<'a> {
+    pub fn caves(&self) -> &[&'a str] {
+        &self.0
+    }
+
+    /// The number of caves visited, counting repeats.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Formats as `start,A,b,end`.
+impl std::fmt::Display for Path<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.join(","))
+    }
+}
+
+/// An iterator over the paths through a [`Graph`], from [`Graph::walk`].
+#[derive(Debug)]
+pub struct Walk<'a> {
+    graph: &'a Graph,
+    policy: Policy<'a>,
+    stack: Vec<Traversal<'a>>,
+    through: Vec<&'a str>,
+    max_len: Option<usize>,
+}
+
+impl<'a> Walk<'a> {
+    /// Only yields paths that visit `cave`. Can be repeated for several caves.
+    pub fn through(mut self, cave: &'a str) -> Self {
+        self.through.push(cave);
+        self
+    }
+
+    /// Only yields paths of at most `max_len` caves, without exploring longer ones.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+}
+
+impl<'a> Iterator for Walk<'a> {
+    type Item = Path<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(traversal) = self.stack.pop() {
+            if traversal.node() == self.policy.end {
+                if self
+                    .through
+                    .iter()
+                    .all(|cave| traversal.path.contains(cave))
+                {
+                    return Some(Path(traversal.path));
+                }
+                continue;
+            }
+            if self.max_len.is_some_and(|max| traversal.path.len() >= max) {
+                continue;
+            }
+
+            let Some(children) = self.graph.inner.get(traversal.node()) else {
+                continue;
+            };
+            // pushed in reverse so paths come out in the order the edges were listed
+            for child in children.iter().rev().map(String::as_str) {
+                if !self.policy.allows(child) {
+                    continue;
+                }
+                let revisited = if is_small(child) {
+                    let visits = traversal.path.iter().filter(|&&cave| cave == child).count();
+                    match self.policy.enter_small(visits as u32, traversal.revisited) {
+                        Some(revisited) => revisited,
+                        None => continue,
+                    }
+                } else {
+                    traversal.revisited
+                };
+                self.stack.push(traversal.augment(child, revisited));
+            }
+        }
+        None
+    }
+}
+
+impl Graph {
+    fn new() -> Self {
+        Self {
+            inner: HashMap::new(),
+        }
+    }
+
+    fn add_edge(&mut self, left: String, right: String) {
+        self.inner.entry(left).or_default().push(right);
+    }
+
+    fn from_input(s: &str) -> Result<Graph, ParseError> {
+        let mut graph = Self::new();
+        parse::lines(s, |line| parse::split_once(line, "-"))?
+            .into_iter()
+            .for_each(|(begin, end)| {
+                graph.add_edge(begin.to_owned(), end.to_owned());
+                graph.add_edge(end.to_owned(), begin.to_owned());
+            });
+
+        Ok(graph)
+    }
+
+    /// Iterates over the paths `policy` allows, depth first.
+    pub fn walk<'a>(&'a self, policy: Policy<'a>) -> Walk<'a> {
+        let stack = match policy.forbidden.contains(&policy.start) {
+            true => Vec::new(),
+            false => vec![Traversal::new(policy.start)],
+        };
+        Walk {
+            graph: self,
+            policy,
+            stack,
+            through: Vec::new(),
+            max_len: None,
+        }
+    }
+
+    /// Counts the paths `policy` allows by walking every one of them.
+    ///
+    /// See [`count_paths`](Self::count_paths) for a faster way to count them.
+    pub fn paths(&self, policy: Policy<'_>) -> usize {
+        self.walk(policy).count()
+    }
+}
+
+/// A [`Graph`] with its caves interned to ids, for counting paths quickly.
+struct Interned<'a> {
+    policy: Policy<'a>,
+    /// The caves each cave leads to, leaving out the ones the policy rules out.
+    neighbours: Vec<Vec<usize>>,
+    /// Where each small cave's visit count is kept in [`State::visits`].
+    small: Vec<Option<u32>>,
+    /// The bits each visit count takes up.
+    width: u32,
+    start: usize,
+    end: usize,
+}
+
+/// Where a path has got to, which is all that decides how it can carry on.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    cave: usize,
+    /// The number of visits to each small cave, packed together.
+    visits: u128,
+    revisited: u32,
+}
+
+impl<'a> Interned<'a> {
+    /// Interns `graph`, or returns [`None`] if the visit counts of its small
+    /// caves don't fit in a `u128`.
+    fn new(graph: &'a Graph, policy: Policy<'a>) -> Option<Self> {
+        let mut names: Vec<&str> = graph
+            .inner
+            .iter()
+            .flat_map(|(cave, children)| std::iter::once(cave).chain(children))
+            .map(String::as_str)
+            .chain([policy.start, policy.end])
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        let ids: HashMap<&str, usize> = names.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+
+        let width = u32::BITS - policy.max_visits.max(1).leading_zeros();
+        let mut shifts = (0..u128::BITS).step_by(width as usize);
+        let small = names
+            .iter()
+            .map(|&cave| {
+                if !is_small(cave) {
+                    Some(None)
+                } else {
+                    shifts
+                        .next()
+                        .filter(|shift| shift + width <= u128::BITS)
+                        .map(Some)
+                }
+            })
+            .collect::<Option<_>>()?;
+        let neighbours = names
+            .iter()
+            .map(|&cave| match graph.inner.get(cave) {
+                Some(children) => children
+                    .iter()
+                    .filter(|child| policy.allows(child))
+                    .map(|child| ids[child.as_str()])
+                    .collect(),
+                None => Vec::new(),
+            })
+            .collect();
+
+        Some(Self {
+            policy,
+            neighbours,
+            small,
+            width,
+            start: ids[policy.start],
+            end: ids[policy.end],
+        })
+    }
+
+    fn count(&self, state: State, memo: &mut HashMap<State, usize>) -> usize {
+        if state.cave == self.end {
+            return 1;
+        }
+        if let Some(&paths) = memo.get(&state) {
+            return paths;
+        }
+
+        let mask = (1 << self.width) - 1;
+        let mut paths = 0;
+        for &cave in &self.neighbours[state.cave] {
+            let next = match self.small[cave] {
+                Some(shift) => {
+                    let visits = (state.visits >> shift) & mask;
+                    match self.policy.enter_small(visits as u32, state.revisited) {
+                        Some(revisited) => State {
+                            cave,
+                            visits: state.visits + (1 << shift),
+                            revisited,
+                        },
+                        None => continue,
+                    }
+                }
+                None => State { cave, ..state },
+            };
+            paths += self.count(next, memo);
+        }
+
+        memo.insert(state, paths);
+        paths
+    }
+}
+
+impl Graph {
+    /// Counts the same paths as [`paths`](Self::paths), without walking each one.
+    ///
+    /// Paths are counted from each cave once per combination of small cave
+    /// visit counts so far, and those counts are shared.
+    pub fn count_paths(&self, policy: Policy<'_>) -> usize {
+        if policy.forbidden.contains(&policy.start) {
+            return 0;
+        }
+        match Interned::new(self, policy) {
+            Some(caves) => {
+                let start = State {
+                    cave: caves.start,
+                    visits: 0,
+                    revisited: 0,
+                };
+                caves.count(start, &mut HashMap::new())
+            }
+            None => self.paths(policy),
+        }
+    }
+}
+
+fn solve_part1(input: &Graph) -> usize {
+    input.count_paths(Policy::SINGLE_VISIT)
+}
+
+fn solve_part2(input: &Graph) -> usize {
+    input.count_paths(Policy::ONE_REVISIT)
+}
+
+fn prepare_input(input: &str) -> Result<Graph, ParseError> {
+    Graph::from_input(input.trim_end())
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Graph;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input), 10);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), 36);
+    }
+
+    const LARGER: &str = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc
+";
+
+    const LARGEST: &str = "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+";
+
+    #[test]
+    fn larger_examples() {
+        let input = Day12::parse(LARGER).unwrap();
+        assert_eq!((Day12::part1(&input), Day12::part2(&input)), (19, 103));
+
+        let input = Day12::parse(LARGEST).unwrap();
+        assert_eq!((Day12::part1(&input), Day12::part2(&input)), (226, 3509));
+    }
+
+    #[test]
+    fn counting_matches_walking() {
+        for example in [EXAMPLE, LARGER, LARGEST] {
+            let input = Day12::parse(example).unwrap();
+            for policy in [Policy::SINGLE_VISIT, Policy::ONE_REVISIT] {
+                assert_eq!(input.count_paths(policy), input.paths(policy));
+            }
+        }
+    }
+
+    #[test]
+    fn walks_concrete_paths() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        let paths: Vec<String> = input
+            .walk(Policy::SINGLE_VISIT)
+            .map(|path| path.to_string())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "start,A,c,A,b,A,end",
+                "start,A,c,A,b,end",
+                "start,A,c,A,end",
+                "start,A,b,A,c,A,end",
+                "start,A,b,A,end",
+                "start,A,b,end",
+                "start,A,end",
+                "start,b,A,c,A,end",
+                "start,b,A,end",
+                "start,b,end",
+            ]
+        );
+
+        let through_c: Vec<String> = input
+            .walk(Policy::SINGLE_VISIT)
+            .through("c")
+            .max_len(6)
+            .map(|path| path.to_string())
+            .collect();
+        assert_eq!(
+            through_c,
+            ["start,A,c,A,b,end", "start,A,c,A,end", "start,b,A,c,A,end"]
+        );
+
+        assert!(input
+            .walk(Policy::ONE_REVISIT)
+            .max_len(4)
+            .all(|path| path.len() <= 4));
+        assert_eq!(
+            input
+                .walk(Policy::ONE_REVISIT)
+                .through("d")
+                .through("c")
+                .count(),
+            4
+        );
+        let twice = input
+            .walk(Policy::ONE_REVISIT)
+            .filter(|path| path.caves().iter().filter(|&&cave| cave == "b").count() == 2);
+        assert_eq!(twice.count(), 20);
+    }
+
+    #[test]
+    fn counts_dense_graphs() {
+        // every small cave joined to two big caves, and each other
+        let mut caves = vec!["start-A".to_string(), "A-end".to_string()];
+        for small in 'a'..='j' {
+            caves.push(format!("{}-A", small));
+            caves.push(format!("{}-B", small));
+            for other in 'a'..small {
+                caves.push(format!("{}-{}", small, other));
+            }
+        }
+        let input = Day12::parse(&caves.join("\n")).unwrap();
+        let paths = input.count_paths(Policy::SINGLE_VISIT);
+        assert!(paths > 1_000_000_000);
+        assert!(input.count_paths(Policy::ONE_REVISIT) > paths);
+    }
+
+    #[test]
+    fn policies_change_the_rules() {
+        let input = Day12::parse(LARGER).unwrap();
+        let policies = [
+            Policy {
+                revisits: 2,
+                ..Policy::ONE_REVISIT
+            },
+            Policy {
+                max_visits: 3,
+                ..Policy::ONE_REVISIT
+            },
+            Policy {
+                max_visits: 3,
+                revisits: 2,
+                ..Policy::ONE_REVISIT
+            },
+            Policy {
+                forbidden: &["kj"],
+                ..Policy::ONE_REVISIT
+            },
+            Policy {
+                start: "end",
+                end: "start",
+                ..Policy::SINGLE_VISIT
+            },
+            Policy {
+                start: "sa",
+                end: "LN",
+                ..Policy::ONE_REVISIT
+            },
+        ];
+        for policy in policies {
+            assert_eq!(
+                input.count_paths(policy),
+                input.paths(policy),
+                "{:?}",
+                policy
+            );
+        }
+
+        let counts: Vec<_> = policies[..3]
+            .iter()
+            .map(|&policy| input.count_paths(policy))
+            .collect();
+        assert_eq!(counts, [247, 305, 3191]);
+
+        // with no revisits, allowing more visits changes nothing
+        let once = input.paths(Policy::SINGLE_VISIT);
+        let no_revisits = Policy {
+            revisits: 0,
+            ..Policy::ONE_REVISIT
+        };
+        assert_eq!(input.count_paths(no_revisits), once);
+        // the graph is undirected, so paths can be walked backwards
+        let backwards = Policy {
+            start: "end",
+            end: "start",
+            ..Policy::SINGLE_VISIT
+        };
+        assert_eq!(input.count_paths(backwards), once);
+
+        assert!(input
+            .walk(policies[3])
+            .all(|path| !path.caves().contains(&"kj")));
+        let start_forbidden = Policy {
+            forbidden: &["start"],
+            ..Policy::SINGLE_VISIT
+        };
+        assert_eq!(input.count_paths(start_forbidden), 0);
+        assert_eq!(input.walk(start_forbidden).count(), 0);
+    }
+}
@@ -1,106 +1,86 @@
-use std::collections::{HashMap, HashSet};
-
-#[derive(Debug, Clone)]
-struct Graph {
-    inner: HashMap<String, Vec<String>>,
-}
-
-#[inline]
-fn is_small(a: &str) -> bool {
-    a.chars().all(|x| x.is_ascii_lowercase())
-}
-
-#[derive(Debug)]
-struct Traversal<'a> {
-    node: &'a str,
-    path: HashSet<&'a str>,
-    second: bool,
-}
-
-impl<'a> Traversal<'a> {
-    fn new(node: &'a str, second: bool) -> Self {
-        let mut path = HashSet::with_capacity(1);
-        path.insert(node);
-        Self { path, node, second }
+use aoc_common::{input::Source, runner, solution::Solution};
+use day_12::{Day12, Policy};
+use std::str::FromStr;
+
+const USAGE: &str = "usage: day-12 (--paths | --count) [--revisit] [--max-visits N] \
+                     [--revisits N] [--forbid CAVE]... [--start CAVE] [--end CAVE] \
+                     [--through CAVE]... [--max-len N] [PATH | -]";
+
+/// `day-12 --paths [OPTIONS] [PATH | -]` prints every path, one per line,
+/// instead of the answers, and `day-12 --count [OPTIONS] [PATH | -]` counts them.
+///
+/// Small caves are visited once unless the options say otherwise:
+/// `--revisit` allows part 2's single second visit to a small cave, while
+/// `--max-visits` and `--revisits` set the limits directly. `--forbid`,
+/// `--start` and `--end` pick the caves, and `--through` and `--max-len`
+/// filter the listed paths.
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let count = match args.first().map(String::as_str) {
+        Some("--paths") => false,
+        Some("--count") => true,
+        _ => return runner::main::<Day12>(),
+    };
+
+    let mut policy = Policy::SINGLE_VISIT;
+    let mut forbidden = Vec::new();
+    let mut through = Vec::new();
+    let mut max_len = None;
+    let mut path = None;
+
+    let mut rest = args[1..].iter().map(String::as_str);
+    while let Some(arg) = rest.next() {
+        match arg {
+            "--revisit" => {
+                policy.max_visits = Policy::ONE_REVISIT.max_visits;
+                policy.revisits = Policy::ONE_REVISIT.revisits;
+            }
+            "--max-visits" => policy.max_visits = number(rest.next(), arg),
+            "--revisits" => policy.revisits = number(rest.next(), arg),
+            "--forbid" => forbidden.push(required(rest.next(), arg)),
+            "--start" => policy.start = required(rest.next(), arg),
+            "--end" => policy.end = required(rest.next(), arg),
+            "--through" => through.push(required(rest.next(), arg)),
+            "--max-len" => max_len = Some(number(rest.next(), arg)),
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(arg),
+            _ => runner::fail(USAGE),
+        }
     }
+    policy.forbidden = &forbidden;
 
-    fn augment(&self, node: &'a str, second: bool) -> Self {
-        let mut path = self.path.clone();
-        path.insert(node);
-        Self { node, path, second }
-    }
-}
+    let source = Source::from_arg(path, Day12::INPUT);
+    let input = source.read().unwrap_or_else(|error| runner::fail(error));
+    let graph =
+        Day12::parse(&input).unwrap_or_else(|error| runner::fail(format!("{}: {}", source, error)));
 
-impl Graph {
-    fn new() -> Self {
-        Self {
-            inner: HashMap::new(),
-        }
+    if count && through.is_empty() && max_len.is_none() {
+        println!("{}", graph.count_paths(policy));
+        return;
     }
 
-    fn add_edge(&mut self, left: String, right: String) {
-        self.inner.entry(left).or_default().push(right);
+    let mut walk = graph.walk(policy);
+    for cave in through {
+        walk = walk.through(cave);
     }
-
-    fn from_input(s: &str) -> Graph {
-        let mut graph = Self::new();
-        s.lines()
-            .filter_map(|s| s.split_once('-'))
-            .for_each(|(begin, end)| {
-                if end != "start" {
-                    graph.add_edge(begin.to_owned(), end.to_owned());
-                }
-                if begin != "start" {
-                    graph.add_edge(end.to_owned(), begin.to_owned());
-                }
-            });
-
-        graph
+    if let Some(max_len) = max_len {
+        walk = walk.max_len(max_len);
     }
-
-    fn paths<'a>(&'a self, second: bool) -> usize {
-        let mut paths = 0;
-        let mut stack: Vec<Traversal<'a>> = vec![Traversal::new("start", second)];
-
-        while let Some(traversal) = stack.pop() {
-            if let Some(children) = self.inner.get(traversal.node) {
-                for child in children {
-                    if child == "end" {
-                        // dbg!(&traversal);
-                        paths += 1;
-                    } else if is_small(child.as_str()) && traversal.path.contains(child.as_str()) {
-                        if !traversal.second {
-                            stack.push(traversal.augment(child, true));
-                        }
-                    } else {
-                        stack.push(traversal.augment(child, traversal.second));
-                    }
-                }
-            }
+    if count {
+        println!("{}", walk.count());
+    } else {
+        for path in walk {
+            println!("{}", path);
         }
-        paths
     }
 }
 
-fn solve_part1(input: Graph) -> usize {
-    input.paths(true)
-}
-
-fn solve_part2(input: Graph) -> usize {
-    input.paths(false)
+fn required<'a>(value: Option<&'a str>, flag: &str) -> &'a str {
+    value.unwrap_or_else(|| runner::fail(format!("{} needs a value", flag)))
 }
 
-fn prepare_input() -> Graph {
-    Graph::from_input(include_str!("../input.txt"))
-}
-
-fn main() {
-    let input_graph = prepare_input();
-
-    let solution1 = solve_part1(input_graph.clone());
-
-    let solution2 = solve_part2(input_graph.clone());
-
-    println!("PART 1: {}", solution1);
-    println!("PART 2: {}", solution2);
+fn number<T: FromStr>(value: Option<&str>, flag: &str) -> T {
+    let value = required(value, flag);
+    value
+        .parse()
+        .unwrap_or_else(|_| runner::fail(format!("invalid {} `{}`", flag, value)))
 }
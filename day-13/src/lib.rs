@@ -0,0 +1,253 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use std::fmt::{Debug, Display, Write};
+use std::{collections::HashSet, str::FromStr};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+impl Debug for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", &self.x, &self.y)
+    }
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Point, Self::Err> {
+        let (x, y) = parse::split_once(s, ",")?;
+        Ok(Point {
+            x: parse::value(x)?,
+            y: parse::value(y).map_err(|e| e.within(s, y))?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum Fold {
+    X(u32),
+    Y(u32),
+}
+
+impl FromStr for Fold {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Fold, Self::Err> {
+        let rest = s
+            .strip_prefix("fold along ")
+            .ok_or_else(|| ParseError::new("expected `fold along`", s))?;
+        let (coord, value) = parse::split_once(rest, "=").map_err(|e| e.within(s, rest))?;
+        let value = parse::value(value).map_err(|e| e.within(s, value))?;
+        match coord {
+            "x" => Ok(Self::X(value)),
+            "y" => Ok(Self::Y(value)),
+            _ => Err(ParseError::new("invalid fold axis", coord).within(s, coord)),
+        }
+    }
+}
+
+impl Fold {
+    fn apply<'a, T>(&self, points: T) -> HashSet<Point>
+    where
+        T: Iterator<Item = &'a Point>,
+    {
+        match self {
+            Fold::X(x) => points
+                .map(|f| {
+                    if f.x < *x {
+                        *f
+                    } else {
+                        Point {
+                            x: 2 * x - f.x,
+                            y: f.y,
+                        }
+                    }
+                })
+                .collect(),
+            Fold::Y(y) => points
+                .map(|f| {
+                    if f.y < *y {
+                        *f
+                    } else {
+                        Point {
+                            x: f.x,
+                            y: 2 * y - f.y,
+                        }
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Paper {
+    coords: HashSet<Point>,
+    folds: Vec<Fold>,
+}
+
+impl Paper {
+    fn new(s: &str) -> Result<Self, ParseError> {
+        let (coords, fold_lines) = s
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::new("expected a blank line before the folds", ""))?;
+        let coords: Vec<Point> = parse::lines(coords, str::parse)?;
+        let coords = HashSet::from_iter(coords);
+
+        let folds: Vec<Fold> =
+            parse::lines(fold_lines, str::parse).map_err(|e| e.within(s, fold_lines))?;
+        if folds.is_empty() {
+            return Err(ParseError::new("expected at least one fold", "").within(s, fold_lines));
+        }
+
+        Ok(Self { coords, folds })
+    }
+
+    fn fold(&mut self, all: bool) {
+        let folds = if all {
+            self.folds.iter()
+        } else {
+            self.folds[0..1].iter()
+        };
+        for fold in folds {
+            self.coords = fold.apply(self.coords.iter());
+        }
+    }
+}
+
+impl Display for Paper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // ugly
+        let max_x = self
+            .coords
+            .iter()
+            .max_by_key(|f| f.x)
+            .map(|p| p.x)
+            .unwrap_or_default();
+        let min_x = self
+            .coords
+            .iter()
+            .min_by_key(|f| f.x)
+            .map(|p| p.x)
+            .unwrap_or_default();
+
+        let max_y = self
+            .coords
+            .iter()
+            .max_by_key(|f| f.y)
+            .map(|p| p.y)
+            .unwrap_or_default();
+        let min_y = self
+            .coords
+            .iter()
+            .min_by_key(|f| f.y)
+            .map(|p| p.y)
+            .unwrap_or_default();
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                if self.coords.contains(&Point { x, y }) {
+                    f.write_char('X').unwrap();
+                } else {
+                    f.write_char(' ').unwrap();
+                }
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+fn solve_part1(input: &Paper) -> usize {
+    let mut paper = input.clone();
+    paper.fold(false);
+    paper.coords.len()
+}
+
+fn solve_part2(input: &Paper) -> Paper {
+    let mut paper = input.clone();
+    paper.fold(true);
+
+    paper
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Paper;
+    type Part1 = usize;
+    type Part2 = Paper;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Paper::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&input), 17);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            Day13::part2(&input).to_string(),
+            "XXXXX\nX   X\nX   X\nX   X\nXXXXX\n"
+        );
+    }
+
+    #[test]
+    fn bad_fold_is_located() {
+        let input = EXAMPLE.replace("fold along x=5", "fold along z=5");
+        let error = Day13::parse(&input).unwrap_err();
+        assert_eq!((error.line(), error.column(), error.text()), (21, 12, "z"));
+    }
+}
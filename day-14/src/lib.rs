@@ -0,0 +1,496 @@
+use aoc_common::{
+    bundled_input,
+    counter::{Count, Counter},
+    grid::Grid,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use num_bigint::BigUint;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Write,
+    str::FromStr,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Element(char, char);
+
+impl std::fmt::Debug for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.0)?;
+        f.write_char(self.1)
+    }
+}
+
+impl FromStr for Element {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(a), Some(b), None) => Ok(Self(a, b)),
+            _ => Err(ParseError::new("expected a pair of elements", s)),
+        }
+    }
+}
+
+impl From<&[u8]> for Element {
+    fn from(x: &[u8]) -> Self {
+        Self(x[0] as char, x[1] as char)
+    }
+}
+
+/// A pair insertion rule, e.g. `CH -> B`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    pub pair: (char, char),
+    pub insert: char,
+}
+
+impl std::fmt::Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{} -> {}", self.pair.0, self.pair.1, self.insert)
+    }
+}
+
+/// A polymer template and its pair insertion rules.
+///
+/// Pairs without a rule are left as they are by every step.
+#[derive(Clone)]
+pub struct Polymer {
+    initial: String,
+    counter: Counter<Element>,
+    rules: HashMap<Element, char>,
+}
+
+impl Polymer {
+    fn from_input(s: &str) -> Result<Self, ParseError> {
+        let (start, rules) = s
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::new("expected a blank line after the template", ""))?;
+        if start.is_empty() {
+            return Err(ParseError::new("expected a polymer template", ""));
+        }
+        parse::only(start, "ABCDEFGHIJKLMNOPQRSTUVWXYZ", "an element")?;
+
+        let rules = parse::lines(rules, |line| {
+            let (from, to) = parse::split_once(line, " -> ")?;
+            let from: Element = from.parse()?;
+            let mut to_chars = to.chars();
+            match (to_chars.next(), to_chars.next()) {
+                (Some(middle), None) => Ok((from, middle)),
+                _ => Err(ParseError::new("expected a single element", to).within(line, to)),
+            }
+        })
+        .map_err(|e| e.within(s, rules))?;
+
+        Ok(Self {
+            initial: start.to_owned(),
+            counter: start.as_bytes().windows(2).map(Element::from).collect(),
+            rules: rules.into_iter().collect(),
+        })
+    }
+
+    fn step(&mut self) {
+        self.counter = self
+            .counter
+            .iter()
+            .flat_map(|(&el, &count)| self.produces(el).into_iter().map(move |pair| (pair, count)))
+            .collect();
+    }
+
+    /// Builds the polymer itself after `steps` steps.
+    ///
+    /// Its length roughly doubles every step, so this is only meant for small
+    /// step counts, e.g. to cross-check [`element_counts`](Self::element_counts).
+    pub fn expand(&self, steps: usize) -> String {
+        let mut polymer: Vec<char> = self.initial.chars().collect();
+        for _ in 0..steps {
+            let mut next = Vec::with_capacity(polymer.len() * 2);
+            for pair in polymer.windows(2) {
+                next.push(pair[0]);
+                if let Some(&middle) = self.rules.get(&Element(pair[0], pair[1])) {
+                    next.push(middle);
+                }
+            }
+            next.extend(polymer.last());
+            polymer = next;
+        }
+        polymer.into_iter().collect()
+    }
+
+    /// The rules that never apply, because their pair can't appear at any step.
+    ///
+    /// They're sorted by pair.
+    pub fn unreachable_rules(&self) -> Vec<Rule> {
+        let reachable: HashSet<Element> = self.reachable_pairs().into_iter().collect();
+        let mut rules: Vec<Rule> = self
+            .rules
+            .iter()
+            .filter(|(pair, _)| !reachable.contains(pair))
+            .map(|(pair, &insert)| Rule {
+                pair: (pair.0, pair.1),
+                insert,
+            })
+            .collect();
+        rules.sort_unstable_by_key(|rule| rule.pair);
+        rules
+    }
+
+    /// The pairs one step turns `pair` into, or [`None`] if it has no rule.
+    fn insert(&self, pair: Element) -> Option<[Element; 2]> {
+        let middle = *self.rules.get(&pair)?;
+        Some([Element(pair.0, middle), Element(middle, pair.1)])
+    }
+
+    /// The pairs one step turns `pair` into; a pair without a rule stays as it is.
+    fn produces(&self, pair: Element) -> Vec<Element> {
+        match self.insert(pair) {
+            Some(pairs) => pairs.to_vec(),
+            None => vec![pair],
+        }
+    }
+
+    /// Every pair that can show up at some step, in the order they're first reached.
+    fn reachable_pairs(&self) -> Vec<Element> {
+        let mut pairs = Vec::new();
+        let mut seen = HashSet::new();
+        for pair in self.initial.as_bytes().windows(2).map(Element::from) {
+            if seen.insert(pair) {
+                pairs.push(pair);
+            }
+        }
+
+        let mut next = 0;
+        while let Some(&pair) = pairs.get(next) {
+            next += 1;
+            for produced in self.produces(pair) {
+                if seen.insert(produced) {
+                    pairs.push(produced);
+                }
+            }
+        }
+        pairs
+    }
+
+    /// Counts each element after `steps` steps, exactly.
+    ///
+    /// This raises the pair-transition matrix to the `steps`-th power by
+    /// repeated squaring, so the number of matrix products only grows with the
+    /// number of bits in `steps`. The counts themselves roughly double every
+    /// step though, so this is practical for thousands of steps, not trillions.
+    pub fn element_counts(&self, steps: u64) -> Counter<char, BigUint> {
+        self.grow(steps, &Exact).positive()
+    }
+
+    /// Counts each element after `steps` steps, modulo `modulus` (typically a
+    /// large prime). Works for any number of steps. Panics if `modulus` is zero.
+    ///
+    /// Every element that can ever appear gets a count, even one that's zero.
+    pub fn element_counts_modulo(&self, steps: u64, modulus: u64) -> Counter<char, u64> {
+        assert!(modulus > 0, "the modulus must be positive");
+        self.grow(steps, &Modulo(modulus))
+    }
+
+    fn grow<A>(&self, mut steps: u64, arithmetic: &A) -> Counter<char, A::Value>
+    where
+        A: Arithmetic,
+        A::Value: Count,
+    {
+        let pairs = self.reachable_pairs();
+        let index: HashMap<Element, usize> = pairs
+            .iter()
+            .enumerate()
+            .map(|(i, &pair)| (pair, i))
+            .collect();
+
+        // `transition[(from, to)]` is how many `to` pairs a `from` pair makes in one step
+        let mut transition = Grid::from_fn(pairs.len(), pairs.len(), |_, _| 0);
+        for (from, &pair) in pairs.iter().enumerate() {
+            for produced in self.produces(pair) {
+                transition[(from, index[&produced])] += 1;
+            }
+        }
+        let mut power = Grid::from_fn(pairs.len(), pairs.len(), |from, to| {
+            arithmetic.value(transition[(from, to)])
+        });
+
+        let template: Counter<Element> = self
+            .initial
+            .as_bytes()
+            .windows(2)
+            .map(Element::from)
+            .collect();
+        let mut counts: Vec<_> = pairs
+            .iter()
+            .map(|pair| arithmetic.value(template[pair] as u64))
+            .collect();
+
+        while steps > 0 {
+            if steps & 1 == 1 {
+                counts = apply_transition(&counts, &power, arithmetic);
+            }
+            steps >>= 1;
+            if steps > 0 {
+                power = multiply(&power, &power, arithmetic);
+            }
+        }
+
+        // Every element starts exactly one pair, apart from the last one.
+        let one = arithmetic.value(1);
+        let mut elements = BTreeMap::new();
+        for (pair, count) in pairs.iter().zip(&counts) {
+            let total = elements
+                .entry(pair.0)
+                .or_insert_with(|| arithmetic.value(0));
+            *total = arithmetic.mul_add(total, count, &one);
+        }
+        let last = self.initial.chars().next_back().unwrap();
+        let total = elements.entry(last).or_insert_with(|| arithmetic.value(0));
+        *total = arithmetic.mul_add(total, &one, &one);
+
+        elements.into_iter().collect()
+    }
+
+    fn apply(&mut self, count: usize) -> Counter<char> {
+        for _ in 0..count {
+            self.step();
+        }
+
+        let mut result: Counter<char> = self
+            .counter
+            .iter()
+            .map(|(el, count)| (el.0, *count))
+            .collect();
+        let last = self.initial.as_bytes().last().copied().unwrap() as char;
+        *result.entry(last) += 1;
+        result
+    }
+}
+
+/// The number system [`Polymer::grow`] counts in.
+trait Arithmetic {
+    type Value: Clone;
+
+    fn value(&self, n: u64) -> Self::Value;
+
+    /// `sum + a * b`
+    fn mul_add(&self, sum: &Self::Value, a: &Self::Value, b: &Self::Value) -> Self::Value;
+}
+
+struct Exact;
+
+impl Arithmetic for Exact {
+    type Value = BigUint;
+
+    fn value(&self, n: u64) -> BigUint {
+        n.into()
+    }
+
+    fn mul_add(&self, sum: &BigUint, a: &BigUint, b: &BigUint) -> BigUint {
+        sum + a * b
+    }
+}
+
+struct Modulo(u64);
+
+impl Arithmetic for Modulo {
+    type Value = u64;
+
+    fn value(&self, n: u64) -> u64 {
+        n % self.0
+    }
+
+    fn mul_add(&self, sum: &u64, a: &u64, b: &u64) -> u64 {
+        // every value is below the modulus, so this can't overflow
+        ((*sum as u128 + *a as u128 * *b as u128) % self.0 as u128) as u64
+    }
+}
+
+/// `lhs * rhs` for square matrices.
+fn multiply<A: Arithmetic>(
+    lhs: &Grid<A::Value>,
+    rhs: &Grid<A::Value>,
+    arithmetic: &A,
+) -> Grid<A::Value> {
+    let size = lhs.width();
+    Grid::from_fn(size, size, |x, y| {
+        (0..size).fold(arithmetic.value(0), |sum, k| {
+            arithmetic.mul_add(&sum, &lhs[(x, k)], &rhs[(k, y)])
+        })
+    })
+}
+
+/// The pair counts after applying `transition` to `counts`.
+fn apply_transition<A: Arithmetic>(
+    counts: &[A::Value],
+    transition: &Grid<A::Value>,
+    arithmetic: &A,
+) -> Vec<A::Value> {
+    (0..counts.len())
+        .map(|to| {
+            counts
+                .iter()
+                .enumerate()
+                .fold(arithmetic.value(0), |sum, (from, count)| {
+                    arithmetic.mul_add(&sum, count, &transition[(from, to)])
+                })
+        })
+        .collect()
+}
+
+fn solve(input: &Polymer, count: usize) -> i64 {
+    let mut polymer = input.clone();
+    let counts = polymer.apply(count);
+    let top = counts.top().unwrap();
+    let bottom = counts.bottom().unwrap();
+    top.1 - bottom.1
+}
+
+fn part1(input: &Polymer) -> i64 {
+    solve(input, 10)
+}
+
+fn part2(input: &Polymer) -> i64 {
+    solve(input, 40)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Polymer;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Polymer::from_input(input.trim_end())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part1(&input), 1588);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part2(&input), 2188189693529);
+    }
+
+    #[test]
+    fn expansion_matches_the_puzzle() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(input.expand(0), "NNCB");
+        assert_eq!(input.expand(2), "NBCCNBBBCBHCB");
+        assert_eq!(
+            input.expand(4),
+            "NBBNBNBBCCNBCNCCNBBNBBNBBBNBBNBBCBHCBHHNHCBBCBHCB"
+        );
+        assert_eq!(input.expand(10).len(), 3073);
+
+        for steps in [1, 5, 10] {
+            let expanded: Counter<char> = input.expand(steps).chars().collect();
+            assert_eq!(expanded, input.clone().apply(steps));
+            let exact: Counter<char, BigUint> = input.expand(steps).chars().collect();
+            assert_eq!(exact, input.element_counts(steps as u64));
+        }
+    }
+
+    #[test]
+    fn pairs_without_rules_pass_through() {
+        let input = Day14::parse("ABC\n\nAB -> A\nCC -> A\nCA -> B").unwrap();
+        assert_eq!(input.expand(1), "AABC");
+        assert_eq!(input.expand(2), "AAABC");
+
+        let stepped = input.clone().apply(2);
+        assert_eq!(stepped, input.expand(2).chars().collect());
+        assert_eq!(stepped[&'A'], 3);
+
+        let unreachable: Vec<_> = input
+            .unreachable_rules()
+            .iter()
+            .map(Rule::to_string)
+            .collect();
+        assert_eq!(unreachable, ["CA -> B", "CC -> A"]);
+        assert!(Day14::parse(EXAMPLE)
+            .unwrap()
+            .unreachable_rules()
+            .is_empty());
+    }
+
+    #[test]
+    fn matrix_power_matches_stepping() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        for steps in [0, 1, 10, 40] {
+            let stepped = input.clone().apply(steps);
+            let grown = input.element_counts(steps as u64);
+            assert_eq!(grown.len(), stepped.len());
+            for (element, count) in stepped.iter() {
+                assert_eq!(
+                    grown[element],
+                    BigUint::from(*count as u64),
+                    "step {}",
+                    steps
+                );
+            }
+        }
+
+        let counts = input.element_counts(40);
+        let spread = counts.top().unwrap().1 - counts.bottom().unwrap().1;
+        assert_eq!(spread, BigUint::from(2188189693529u64));
+    }
+
+    #[test]
+    fn counts_modulo_a_prime() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        let prime = 1_000_000_007;
+        let exact = input.element_counts(500);
+        let modular = input.element_counts_modulo(500, prime);
+        for (element, count) in exact.iter() {
+            assert_eq!(BigUint::from(modular[element]), count % prime);
+        }
+
+        // far too many steps to count exactly
+        let huge = input.element_counts_modulo(1_000_000_000_000, prime);
+        assert_eq!(huge.len(), 4);
+        assert!(huge.values().all(|&count| count < prime));
+    }
+}
@@ -1,99 +1,77 @@
-mod counter;
+use aoc_common::{counter::Counter, input::Source, runner, solution::Solution};
+use day_14::{Day14, Polymer};
+use num_bigint::BigUint;
 
-use counter::Counter;
-use std::{collections::HashMap, fmt::Write};
+const USAGE: &str = "usage: day-14 [--steps N [--modulo P] | --expand N] [PATH | -]";
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Element(char, char);
-
-impl std::fmt::Debug for Element {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_char(self.0)?;
-        f.write_char(self.1)
-    }
-}
-
-impl From<&str> for Element {
-    fn from(s: &str) -> Self {
-        let mut chars = s.chars();
-        Self(chars.next().unwrap(), chars.next().unwrap())
-    }
-}
-
-impl From<&[u8]> for Element {
-    fn from(x: &[u8]) -> Self {
-        Self(x[0] as char, x[1] as char)
-    }
-}
-
-struct Polymer {
-    initial: String,
-    counter: Counter<Element>,
-    rules: HashMap<Element, char>,
-}
+/// `day-14 --steps N [--modulo P] [PATH | -]` prints each element's count after
+/// N steps instead of the answers, exactly or modulo P.
+///
+/// `day-14 --expand N [PATH | -]` prints the polymer itself after N steps,
+/// checking its element counts against the pair counting.
+///
+/// Both warn about rules that can never apply.
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.as_slice() {
+        [flag, steps, rest @ ..] if flag == "--steps" => {
+            let steps = parse_steps(steps);
+            let (modulus, rest) = match rest {
+                [flag, modulus, rest @ ..] if flag == "--modulo" => match modulus.parse() {
+                    Ok(modulus) if modulus > 0 => (Some(modulus), rest),
+                    _ => runner::fail(format!("invalid modulus `{}`", modulus)),
+                },
+                _ => (None, rest),
+            };
 
-impl Polymer {
-    fn from_input(s: &str) -> Self {
-        let (start, rules) = s.split_once("\n\n").unwrap();
-        Self {
-            initial: start.to_owned(),
-            counter: start.as_bytes().windows(2).map(Element::from).collect(),
-            rules: rules
-                .lines()
-                .filter_map(|s| s.split_once(" -> "))
-                .map(|(from, to)| (Element::from(from), to.chars().next().unwrap()))
-                .collect(),
+            let polymer = load(rest);
+            match modulus {
+                Some(modulus) => print!("{}", polymer.element_counts_modulo(steps, modulus)),
+                None => {
+                    let counts = polymer.element_counts(steps);
+                    print!("{}", counts);
+                    if let (Some((_, most)), Some((_, least))) = (counts.top(), counts.bottom()) {
+                        println!("most - least: {}", most - least);
+                    }
+                }
+            }
         }
-    }
+        [flag, steps, rest @ ..] if flag == "--expand" => {
+            let steps = parse_steps(steps);
+            let polymer = load(rest);
 
-    fn step(&mut self) {
-        self.counter = self
-            .counter
-            .iter()
-            .flat_map(|(el, count)| {
-                let middle = self.rules[el];
-                [
-                    (Element(el.0, middle), *count),
-                    (Element(middle, el.1), *count),
-                ]
-            })
-            .collect();
-    }
-
-    fn apply(&mut self, count: usize) -> Counter<char> {
-        for _ in 0..count {
-            self.step();
+            let expanded = polymer.expand(steps as usize);
+            let counted: Counter<char, BigUint> = expanded.chars().collect();
+            if counted != polymer.element_counts(steps) {
+                runner::fail("the expanded polymer disagrees with the pair counts");
+            }
+            println!("{}", expanded);
         }
-
-        let mut result: Counter<char> = self
-            .counter
-            .iter()
-            .map(|(el, count)| (el.0, *count))
-            .collect();
-        let last = self.initial.as_bytes().last().copied().unwrap() as char;
-        *result.entry(last) += 1;
-        result
+        [flag, ..] if flag == "--steps" || flag == "--expand" => runner::fail(USAGE),
+        _ => runner::main::<Day14>(),
     }
 }
 
-fn solve(input: &str, count: usize) -> i64 {
-    let mut polymer = Polymer::from_input(input);
-    let counts = polymer.apply(count);
-    let top = counts.top().unwrap();
-    let bottom = counts.bottom().unwrap();
-    top.1 - bottom.1
+fn parse_steps(steps: &str) -> u64 {
+    steps
+        .parse()
+        .unwrap_or_else(|_| runner::fail(format!("invalid step count `{}`", steps)))
 }
 
-fn part1(input: &str) -> i64 {
-    solve(input, 10)
-}
+/// Reads the polymer from the optional path left in `rest`, warning about
+/// unreachable rules on stderr.
+fn load(rest: &[String]) -> Polymer {
+    if rest.len() > 1 {
+        runner::fail(USAGE);
+    }
 
-fn part2(input: &str) -> i64 {
-    solve(input, 40)
-}
+    let source = Source::from_arg(rest.first().map(String::as_str), Day14::INPUT);
+    let input = source.read().unwrap_or_else(|error| runner::fail(error));
+    let polymer =
+        Day14::parse(&input).unwrap_or_else(|error| runner::fail(format!("{}: {}", source, error)));
 
-fn main() {
-    let input = include_str!("../input.txt").trim_end();
-    println!("PART 1: {:?}", part1(input));
-    println!("PART 2: {:?}", part2(input));
+    for rule in polymer.unreachable_rules() {
+        eprintln!("warning: `{}` never applies", rule);
+    }
+    polymer
 }
@@ -0,0 +1,45 @@
+//! Compares the shortest path backends on the full 500x500 cave.
+//!
+//! Run with `cargo bench -p day-15`, optionally followed by `-- ITERATIONS`.
+
+use aoc_common::{
+    bench, input::Source, path::Algorithm, runner::format_duration, solution::Solution,
+};
+use day_15::{full_cave, safest_path, Day15};
+
+fn main() {
+    let iterations = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(20);
+
+    let input = Source::from_arg(None, Day15::INPUT)
+        .read()
+        .expect("bundled input is readable");
+    let cave = full_cave(&Day15::parse(&input).expect("bundled input parses"));
+
+    println!(
+        "{}x{} cave, {} iterations",
+        cave.width(),
+        cave.height(),
+        iterations
+    );
+    println!(
+        "{:<16} {:>10} {:>10} {:>10}",
+        "BACKEND", "MIN", "MEDIAN", "MAX"
+    );
+    for (name, algorithm) in [
+        ("dijkstra (heap)", Algorithm::Dijkstra),
+        ("a* (heap)", Algorithm::AStar),
+        ("dial (buckets)", Algorithm::Dial { max_step: 9 }),
+    ] {
+        let samples = bench::measure(iterations, || safest_path::<u32>(&cave, algorithm));
+        println!(
+            "{:<16} {:>10} {:>10} {:>10}",
+            name,
+            format_duration(samples.min()),
+            format_duration(samples.median()),
+            format_duration(samples.max())
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use aoc_common::{
+    bundled_input,
+    grid::Grid,
+    parse::{self, ParseError},
+    path::{Algorithm, Cost, Path, PathError},
+    solution::Solution,
+};
+
+/// The lowest total risk route from the top left to the bottom right,
+/// adding up risk in `C`.
+pub fn safest_path<C>(cave: &Grid<u8>, algorithm: Algorithm) -> Result<Path<C>, PathError>
+where
+    C: Cost + From<u8>,
+{
+    let goal = (
+        cave.width().saturating_sub(1),
+        cave.height().saturating_sub(1),
+    );
+    cave.shortest_path((0, 0), goal, algorithm, |_, to| Some(cave[to].into()))
+}
+
+/// The cave repeated five times in each direction, with risk rising by one
+/// per tile to the right or down and wrapping from 9 back to 1.
+pub fn full_cave(cave: &Grid<u8>) -> Grid<u8> {
+    cave.tile(5, 5, |&risk, tx, ty| {
+        ((risk as usize + tx + ty + 8) % 9 + 1) as u8
+    })
+}
+
+/// Risk levels are single digits, so no step costs more than 9.
+const BUCKETS: Algorithm = Algorithm::Dial { max_step: 9 };
+
+fn solve_part1(cave: &Grid<u8>) -> Result<u32, PathError> {
+    safest_path(cave, BUCKETS).map(|path| path.cost)
+}
+
+fn solve_part2(cave: &Grid<u8>) -> Result<u32, PathError> {
+    safest_path(&full_cave(cave), BUCKETS).map(|path| path.cost)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Grid<u8>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let input = input.trim_end();
+        parse::lines(input, |line| {
+            parse::only(line, "123456789", "a risk level from 1 to 9")
+        })?;
+        Grid::single_ascii_number(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input).unwrap_or_else(|error| panic!("no path through the cave: {}", error))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input).unwrap_or_else(|error| panic!("no path through the cave: {}", error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(Day15::part1(&input), 40);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(Day15::part2(&input), 315);
+    }
+
+    #[test]
+    fn backends_agree() {
+        let cave = full_cave(&Day15::parse(EXAMPLE).unwrap());
+        let dijkstra = safest_path::<u32>(&cave, Algorithm::Dijkstra).unwrap();
+        let a_star = safest_path::<u32>(&cave, Algorithm::AStar).unwrap();
+        let dial = safest_path::<u32>(&cave, BUCKETS).unwrap();
+
+        assert_eq!(dijkstra.cost, a_star.cost);
+        assert_eq!(dijkstra.cost, dial.cost);
+        assert_eq!(dijkstra.positions.first(), Some(&(0, 0)));
+        assert_eq!(dijkstra.positions.last(), Some(&(49, 49)));
+    }
+
+    #[test]
+    fn path_is_drawn_over_the_cave() {
+        let cave = Day15::parse(EXAMPLE).unwrap();
+        let path = safest_path::<u16>(&cave, Algorithm::Dijkstra).unwrap();
+
+        assert_eq!(
+            cave.with_path(&path).to_string(),
+            "\
+1.........
+1.........
+2136511...
+......15..
+.......1..
+.......13.
+........2.
+........3.
+........21
+.........1
+"
+        );
+    }
+
+    #[test]
+    fn narrow_cost_types_report_overflow() {
+        let cave = full_cave(&Day15::parse(EXAMPLE).unwrap());
+
+        assert_eq!(
+            safest_path::<u8>(&cave, Algorithm::AStar),
+            Err(PathError::Overflow { cost_type: "u8" })
+        );
+        assert_eq!(
+            safest_path::<u64>(&cave, Algorithm::AStar).map(|path| path.cost),
+            Ok(315)
+        );
+    }
+
+    #[test]
+    fn zero_risk_is_rejected() {
+        let error = Day15::parse("19\n90\n").unwrap_err();
+        assert_eq!(
+            (error.message(), error.line(), error.column()),
+            ("expected a risk level from 1 to 9", 2, 2)
+        );
+    }
+
+    #[test]
+    fn full_cave_wraps_after_9() {
+        let cave = Day15::parse("9").unwrap();
+        let full = full_cave(&cave);
+        let first_row: Vec<_> = (0..5).map(|x| full[(x, 0)]).collect();
+        assert_eq!(first_row, [9, 1, 2, 3, 4]);
+        assert_eq!(full[(4, 4)], 8);
+    }
+}
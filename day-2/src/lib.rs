@@ -0,0 +1,90 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+fn solve_part1(input: &[(String, usize)]) -> usize {
+    let (depth, horizontal) =
+        input
+            .iter()
+            .fold((0, 0), |(depth, horizontal), (dir, val)| {
+                match dir.as_str() {
+                    "forward" => (depth, horizontal + val),
+                    "down" => (depth + val, horizontal),
+                    "up" => (depth - val, horizontal),
+                    _ => (depth, horizontal),
+                }
+            });
+    depth * horizontal
+}
+
+fn solve_part2(input: &[(String, usize)]) -> usize {
+    let (depth, horizontal, _) = input.iter().fold(
+        (0, 0, 0),
+        |(depth, horizontal, aim), (dir, val)| match dir.as_str() {
+            "forward" => (depth + (aim * val), horizontal + val, aim),
+            "down" => (depth, horizontal, aim + val),
+            "up" => (depth, horizontal, aim - val),
+            _ => (depth, horizontal, aim),
+        },
+    );
+    depth * horizontal
+}
+
+fn prepare_input(input: &str) -> Result<Vec<(String, usize)>, ParseError> {
+    parse::lines(input, |line| {
+        let (order, value) = parse::split_once(line, " ")?;
+        let value = parse::value(value).map_err(|e| e.within(line, value))?;
+        Ok((order.to_owned(), value))
+    })
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Vec<(String, usize)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&input), 150);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&input), 900);
+    }
+}
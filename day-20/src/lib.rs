@@ -0,0 +1,231 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use itertools::Itertools;
+use std::{collections::HashSet, fmt::Write};
+
+const ADJACENT: [(isize, isize); 9] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (0, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Rect {
+    x: isize,
+    y: isize,
+    width: isize,
+    height: isize,
+}
+
+impl Rect {
+    #[inline]
+    fn contains(&self, x: isize, y: isize) -> bool {
+        x >= self.x && x <= self.width && y >= self.y && y <= self.height
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Image {
+    algorithm: Vec<bool>,
+    input: HashSet<(isize, isize)>,
+    bounds: Rect,
+    default_pixel: bool,
+}
+
+impl Image {
+    fn from_input(input: &str) -> Result<Self, ParseError> {
+        let (algorithm, s) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::new("expected a blank line after the algorithm", ""))?;
+        let algorithm: Vec<bool> = parse::only(algorithm, "#.", "`#` or `.`")?
+            .chars()
+            .map(|c| c == '#')
+            .collect();
+        if algorithm.len() != 512 {
+            return Err(ParseError::new(
+                format!("expected 512 algorithm cells, found {}", algorithm.len()),
+                "",
+            ));
+        }
+
+        let rows = parse::lines(s, |line| parse::only(line, "#.", "`#` or `.`"))
+            .map_err(|e| e.within(input, s))?;
+        let input: HashSet<(isize, isize)> = rows
+            .into_iter()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|&(_, v)| v == '#')
+                    .map(move |(x, _)| (x as isize, y as isize))
+            })
+            .collect();
+
+        let (x, width) = input
+            .iter()
+            .map(|p| p.0)
+            .minmax()
+            .into_option()
+            .unwrap_or_default();
+        let (y, height) = input
+            .iter()
+            .map(|p| p.1)
+            .minmax()
+            .into_option()
+            .unwrap_or_default();
+
+        Ok(Self {
+            default_pixel: false,
+            algorithm,
+            input,
+            bounds: Rect {
+                x,
+                y,
+                width,
+                height,
+            },
+        })
+    }
+
+    fn get(&self, x: isize, y: isize) -> usize {
+        if !self.bounds.contains(x, y) {
+            self.default_pixel as usize
+        } else {
+            self.input.contains(&(x, y)) as usize
+        }
+    }
+
+    fn binary_at(&self, x: isize, y: isize) -> usize {
+        ADJACENT
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (index, (dx, dy))| {
+                acc | (self.get(x + dx, y + dy) << (8 - index))
+            })
+    }
+
+    fn pixels(&self) -> usize {
+        self.input.len()
+    }
+
+    fn step(&mut self) {
+        let mut new_image = HashSet::with_capacity(
+            self.input.len() + self.bounds.width as usize * 2 + self.bounds.height as usize * 2,
+        );
+
+        for x in self.bounds.x - 1..=self.bounds.width + 1 {
+            for y in self.bounds.y - 1..=self.bounds.height + 1 {
+                let index = self.binary_at(x, y);
+                if self.algorithm[index] {
+                    new_image.insert((x, y));
+                }
+            }
+        }
+
+        if self.default_pixel {
+            self.default_pixel = self.algorithm.last().copied().unwrap_or(false);
+        } else {
+            self.default_pixel = self.algorithm.first().copied().unwrap_or(true);
+        }
+
+        self.input = new_image;
+        self.bounds.x -= 1;
+        self.bounds.y -= 1;
+        self.bounds.width += 1;
+        self.bounds.height += 1;
+    }
+}
+
+impl std::fmt::Display for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let default = if self.default_pixel { '#' } else { '.' };
+        for y in self.bounds.y - 1..=self.bounds.height + 1 {
+            for x in self.bounds.x - 1..=self.bounds.width + 1 {
+                if self.input.contains(&(x, y)) {
+                    f.write_char('#')?;
+                } else {
+                    f.write_char(default)?;
+                }
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+fn solve_part1(image: &mut Image) -> usize {
+    for _ in 0..2 {
+        image.step();
+    }
+    image.pixels()
+}
+
+fn solve_part2(image: &mut Image) -> usize {
+    for _ in 0..50 {
+        image.step();
+    }
+    image.pixels()
+}
+
+fn prepare_input(input: &str) -> Result<Image, ParseError> {
+    Image::from_input(input)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Image;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(&mut input.clone())
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(&mut input.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(Day20::part1(&input), 35);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(Day20::part2(&input), 3351);
+    }
+}
@@ -0,0 +1,341 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use num_bigint::BigUint;
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// The rules a game of Dirac Dice is played by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Spaces on the circular track, numbered from 1.
+    pub board_size: u32,
+    /// The die rolls every value from 1 up to this.
+    pub die_faces: u32,
+    pub rolls_per_turn: u32,
+    /// The first player to reach at least this score wins.
+    pub winning_score: u32,
+}
+
+impl Rules {
+    /// Part 1: a 100-sided deterministic die, playing to 1000.
+    pub const PRACTICE: Self = Self {
+        board_size: 10,
+        die_faces: 100,
+        rolls_per_turn: 3,
+        winning_score: 1000,
+    };
+    /// Part 2: a three-sided Dirac die, playing to 21.
+    pub const DIRAC: Self = Self {
+        board_size: 10,
+        die_faces: 3,
+        rolls_per_turn: 3,
+        winning_score: 21,
+    };
+
+    /// Checks the rules describe a game that can be played.
+    pub fn validate(&self) -> Result<(), RulesError> {
+        if self.board_size == 0 {
+            Err(RulesError::NoSpaces)
+        } else if self.die_faces == 0 {
+            Err(RulesError::NoFaces)
+        } else if self.rolls_per_turn == 0 {
+            Err(RulesError::NoRolls)
+        } else if self.winning_score.checked_add(self.board_size).is_none() {
+            Err(RulesError::ScoreTooHigh)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn advance(&self, position: u32, by: u32) -> u32 {
+        (position - 1 + by) % self.board_size + 1
+    }
+
+    /// How many ways each total of a turn's rolls can come up on a Dirac die.
+    fn roll_totals(&self) -> Vec<(u32, BigUint)> {
+        let mut ways = vec![BigUint::from(1u8)];
+        for _ in 0..self.rolls_per_turn {
+            let mut next = vec![BigUint::ZERO; ways.len() + self.die_faces as usize];
+            for (total, count) in ways.iter().enumerate() {
+                for face in 1..=self.die_faces as usize {
+                    next[total + face] += count;
+                }
+            }
+            ways = next;
+        }
+
+        ways.into_iter()
+            .enumerate()
+            .filter(|(_, count)| *count > BigUint::ZERO)
+            .map(|(total, count)| (total as u32, count))
+            .collect()
+    }
+}
+
+/// Why a set of [`Rules`] can't be played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RulesError {
+    NoSpaces,
+    NoFaces,
+    NoRolls,
+    /// Scores could pass the winning score without fitting in a `u32`.
+    ScoreTooHigh,
+}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RulesError::NoSpaces => f.write_str("the board needs at least one space"),
+            RulesError::NoFaces => f.write_str("the die needs at least one face"),
+            RulesError::NoRolls => f.write_str("players need to roll at least once a turn"),
+            RulesError::ScoreTooHigh => f.write_str("the winning score is too high"),
+        }
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+/// The starting positions of both players.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Game {
+    pub positions: [u32; 2],
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let positions = parse::lines(input, |line| {
+            let (_, position) = parse::split_once(line, "starting position: ")?;
+            match parse::value(position).map_err(|e| e.within(line, position))? {
+                0 => Err(ParseError::new("positions start at 1", position).within(line, position)),
+                position => Ok(position),
+            }
+        })?;
+
+        let positions = positions
+            .try_into()
+            .map_err(|_| ParseError::new("expected exactly two players", input))?;
+        Ok(Self { positions })
+    }
+}
+
+/// A point in a game, which is all that decides how it plays out from there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    positions: [u32; 2],
+    scores: [u32; 2],
+    /// The player about to roll.
+    turn: usize,
+}
+
+impl Game {
+    /// Plays with a die that rolls 1, 2, 3, ... wrapping after its last face.
+    ///
+    /// Returns the losing player's score multiplied by the number of rolls.
+    pub fn deterministic(&self, rules: Rules) -> Result<u64, RulesError> {
+        rules.validate()?;
+        let mut positions = self.positions.map(|p| rules.advance(p, 0));
+        let mut scores = [0; 2];
+        let mut rolls = 0u64;
+        let mut die = (1..=rules.die_faces).cycle();
+
+        for turn in [0, 1].into_iter().cycle() {
+            let total = die
+                .by_ref()
+                .take(rules.rolls_per_turn as usize)
+                .sum::<u32>();
+            rolls += rules.rolls_per_turn as u64;
+
+            positions[turn] = rules.advance(positions[turn], total);
+            scores[turn] += positions[turn];
+            if scores[turn] >= rules.winning_score {
+                return Ok(scores[1 - turn] as u64 * rolls);
+            }
+        }
+        unreachable!("the turns cycle forever")
+    }
+
+    /// Counts the universes each player wins in when every roll splits the universe.
+    ///
+    /// The counts grow quickly with the winning score, so they're exact big integers.
+    pub fn quantum(&self, rules: Rules) -> Result<[BigUint; 2], RulesError> {
+        rules.validate()?;
+        let start = State {
+            positions: self.positions.map(|p| rules.advance(p, 0)),
+            scores: [0; 2],
+            turn: 0,
+        };
+        Ok(wins(
+            &rules,
+            &rules.roll_totals(),
+            start,
+            &mut HashMap::new(),
+        ))
+    }
+}
+
+fn wins(
+    rules: &Rules,
+    totals: &[(u32, BigUint)],
+    state: State,
+    memo: &mut HashMap<State, [BigUint; 2]>,
+) -> [BigUint; 2] {
+    if let Some(wins) = memo.get(&state) {
+        return wins.clone();
+    }
+
+    let mut result = [BigUint::ZERO, BigUint::ZERO];
+    for (total, ways) in totals {
+        let total = *total;
+        let mut next = state;
+        let turn = state.turn;
+        next.positions[turn] = rules.advance(state.positions[turn], total);
+        next.scores[turn] += next.positions[turn];
+
+        if next.scores[turn] >= rules.winning_score {
+            result[turn] += ways;
+        } else {
+            next.turn = 1 - turn;
+            let [a, b] = wins(rules, totals, next, memo);
+            result[0] += a * ways;
+            result[1] += b * ways;
+        }
+    }
+
+    memo.insert(state, result.clone());
+    result
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Game;
+    type Part1 = u64;
+    type Part2 = BigUint;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.trim_end().parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input
+            .deterministic(Rules::PRACTICE)
+            .expect("the practice rules are valid")
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input
+            .quantum(Rules::DIRAC)
+            .expect("the Dirac rules are valid")
+            .into_iter()
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Player 1 starting position: 4
+Player 2 starting position: 8
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part1(&input), 739785);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part2(&input), BigUint::from(444356092776315u64));
+        assert_eq!(
+            input.quantum(Rules::DIRAC).unwrap(),
+            [444356092776315u64, 341960390180808].map(BigUint::from)
+        );
+    }
+
+    #[test]
+    fn variants_follow_the_rules() {
+        let game = Game { positions: [1, 1] };
+        // One roll of a two-sided die moves to space 2 or 3, and 2 points always wins.
+        let rules = Rules {
+            board_size: 10,
+            die_faces: 2,
+            rolls_per_turn: 1,
+            winning_score: 2,
+        };
+        assert_eq!(game.quantum(rules).unwrap(), [2u8, 0].map(BigUint::from));
+        assert_eq!(game.deterministic(rules).unwrap(), 0);
+    }
+
+    #[test]
+    fn high_winning_scores_count_exactly() {
+        let game = Game { positions: [4, 8] };
+        let rules = Rules {
+            winning_score: 60,
+            ..Rules::DIRAC
+        };
+        let [first, second] = game.quantum(rules).unwrap();
+        assert!(first > BigUint::from(u128::MAX));
+        assert!(second > BigUint::from(u128::MAX));
+    }
+
+    #[test]
+    fn unplayable_rules_are_rejected() {
+        let game = Game { positions: [4, 8] };
+        let cases = [
+            (
+                Rules {
+                    board_size: 0,
+                    ..Rules::PRACTICE
+                },
+                RulesError::NoSpaces,
+            ),
+            (
+                Rules {
+                    die_faces: 0,
+                    ..Rules::DIRAC
+                },
+                RulesError::NoFaces,
+            ),
+            (
+                Rules {
+                    rolls_per_turn: 0,
+                    ..Rules::DIRAC
+                },
+                RulesError::NoRolls,
+            ),
+            (
+                Rules {
+                    winning_score: u32::MAX,
+                    ..Rules::PRACTICE
+                },
+                RulesError::ScoreTooHigh,
+            ),
+        ];
+        for (rules, error) in cases {
+            assert_eq!(game.deterministic(rules), Err(error));
+            assert_eq!(game.quantum(rules), Err(error));
+        }
+    }
+
+    #[test]
+    fn missing_player_is_reported() {
+        let error = Day21::parse("Player 1 starting position: 4").unwrap_err();
+        assert_eq!(error.message(), "expected exactly two players");
+    }
+}
@@ -1,3 +1,3 @@
 fn main() {
-    println!("Hello, world!");
+    aoc_common::runner::main::<day_21::Day21>();
 }
@@ -0,0 +1,152 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Command {
+    Forward(i64),
+    Up(i64),
+    Down(i64),
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (cmd, val) = parse::split_once(s, " ")?;
+        let val = parse::value::<i64>(val).map_err(|e| e.within(s, val))?;
+
+        match cmd {
+            "forward" => Ok(Command::Forward(val)),
+            "up" => Ok(Command::Up(val)),
+            "down" => Ok(Command::Down(val)),
+            _ => Err(ParseError::new("unknown command", cmd)),
+        }
+    }
+}
+
+pub trait Submarine {
+    fn execute(&mut self, cmd: &Command);
+    fn run(&mut self, input: &[Command]) {
+        for cmd in input {
+            self.execute(cmd);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SubmarineV1 {
+    x: i64,
+    y: i64,
+}
+
+impl Submarine for SubmarineV1 {
+    fn execute(&mut self, cmd: &Command) {
+        match cmd {
+            Command::Forward(delta) => self.x += delta,
+            Command::Up(delta) => self.y -= delta,
+            Command::Down(delta) => self.y += delta,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SubmarineV2 {
+    x: i64,
+    y: i64,
+    aim: i64,
+}
+
+impl Submarine for SubmarineV2 {
+    fn execute(&mut self, cmd: &Command) {
+        match cmd {
+            Command::Up(delta) => self.aim -= delta,
+            Command::Down(delta) => self.aim += delta,
+            Command::Forward(delta) => {
+                self.x += delta;
+                self.y += self.aim * delta;
+            }
+        }
+    }
+}
+
+pub fn solve_part1(input: &[Command]) -> i64 {
+    let mut submarine = SubmarineV1 { x: 0, y: 0 };
+    submarine.run(input);
+
+    submarine.x * submarine.y
+}
+
+pub fn solve_part2(input: &[Command]) -> i64 {
+    let mut submarine = SubmarineV2 { x: 0, y: 0, aim: 0 };
+    submarine.run(input);
+
+    submarine.x * submarine.y
+}
+fn prepare_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    parse::lines(input, str::parse)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Vec<Command>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&input), 150);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&input), 900);
+    }
+
+    #[test]
+    fn unknown_command_is_reported() {
+        let error = "sideways 2".parse::<Command>().unwrap_err();
+        assert_eq!(error.message(), "unknown command");
+
+        let error = Day2::parse("forward 5\nsideways 2\n").unwrap_err();
+        assert_eq!(
+            (error.line(), error.column(), error.text()),
+            (2, 1, "sideways")
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use std::str::FromStr;
+
+/// Which bit value a [`BitCriteria`] keeps at each position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Select {
+    MostCommon,
+    LeastCommon,
+}
+
+/// A bit criterion for narrowing down the report to a single rating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitCriteria {
+    pub select: Select,
+    /// The bit kept when both values are equally common.
+    pub tie: bool,
+}
+
+impl BitCriteria {
+    pub const OXYGEN_GENERATOR: Self = Self {
+        select: Select::MostCommon,
+        tie: true,
+    };
+    pub const CO2_SCRUBBER: Self = Self {
+        select: Select::LeastCommon,
+        tie: false,
+    };
+
+    /// The bit to keep given how many candidates have each value.
+    fn keep(&self, zeros: usize, ones: usize) -> bool {
+        match (self.select, zeros.cmp(&ones)) {
+            (_, std::cmp::Ordering::Equal) => self.tie,
+            (Select::MostCommon, order) => order.is_lt(),
+            (Select::LeastCommon, order) => order.is_gt(),
+        }
+    }
+}
+
+/// The diagnostic report: binary numbers that are all the same width.
+#[derive(Debug)]
+pub struct Report {
+    numbers: Vec<u32>,
+    width: usize,
+}
+
+impl FromStr for Report {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let width = input.lines().next().map(str::len).unwrap_or_default();
+        if width > 32 {
+            let line = input.lines().next().unwrap_or_default();
+            return Err(ParseError::new("expected at most 32 bits", line));
+        }
+
+        let numbers = parse::lines(input, |line| {
+            parse::only(line, "01", "a binary digit")?;
+            if line.len() != width {
+                let message = format!("expected a line of {} bits", width);
+                return Err(ParseError::new(message, line));
+            }
+            Ok(u32::from_str_radix(line, 2).unwrap_or_default())
+        })?;
+
+        Ok(Self { numbers, width })
+    }
+}
+
+impl Report {
+    /// Gamma and epsilon rates: the most and least common bit in every position.
+    pub fn power_rates(&self) -> (u32, u32) {
+        let gamma = (0..self.width).rev().fold(0, |gamma, bit| {
+            let ones = self.numbers.iter().filter(|&&n| n >> bit & 1 == 1).count();
+            let zeros = self.numbers.len() - ones;
+            gamma << 1 | BitCriteria::OXYGEN_GENERATOR.keep(zeros, ones) as u32
+        });
+        let mask = u32::MAX.checked_shr(32 - self.width as u32).unwrap_or(0);
+
+        (gamma, !gamma & mask)
+    }
+
+    /// Filters the numbers bit by bit, from the most significant, until one is left.
+    ///
+    /// A position where every remaining number has the same bit is skipped, as
+    /// neither value is less common there.
+    pub fn rating(&self, criteria: BitCriteria) -> u32 {
+        let mut candidates = self.numbers.clone();
+
+        for bit in (0..self.width).rev() {
+            if candidates.len() <= 1 {
+                break;
+            }
+
+            let ones = candidates.iter().filter(|&&n| n >> bit & 1 == 1).count();
+            let zeros = candidates.len() - ones;
+            if ones == 0 || zeros == 0 {
+                continue;
+            }
+
+            let keep = criteria.keep(zeros, ones) as u32;
+            candidates.retain(|&n| n >> bit & 1 == keep);
+        }
+
+        candidates.first().copied().unwrap_or_default()
+    }
+}
+
+fn solve_part1(report: &Report) -> u64 {
+    let (gamma, epsilon) = report.power_rates();
+    gamma as u64 * epsilon as u64
+}
+
+fn solve_part2(report: &Report) -> u64 {
+    let oxygen = report.rating(BitCriteria::OXYGEN_GENERATOR);
+    let co2 = report.rating(BitCriteria::CO2_SCRUBBER);
+
+    oxygen as u64 * co2 as u64
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Report;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part1(&input), 198);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part2(&input), 230);
+    }
+
+    #[test]
+    fn criteria_break_ties_as_configured() {
+        let report: Report = "10\n01\n".parse().unwrap();
+        let criteria = |select, tie| BitCriteria { select, tie };
+
+        assert_eq!(report.rating(criteria(Select::MostCommon, true)), 0b10);
+        assert_eq!(report.rating(criteria(Select::MostCommon, false)), 0b01);
+        assert_eq!(report.rating(criteria(Select::LeastCommon, true)), 0b10);
+        assert_eq!(report.rating(criteria(Select::LeastCommon, false)), 0b01);
+    }
+
+    #[test]
+    fn least_common_ignores_bits_every_candidate_shares() {
+        let report: Report = "110\n110\n111\n".parse().unwrap();
+        assert_eq!(report.rating(BitCriteria::CO2_SCRUBBER), 0b111);
+    }
+
+    #[test]
+    fn uneven_lines_are_reported() {
+        let error = Day3::parse("0101\n011\n").unwrap_err();
+        assert_eq!(
+            (error.line(), error.column(), error.message()),
+            (2, 1, "expected a line of 4 bits")
+        );
+    }
+}
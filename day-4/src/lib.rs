@@ -0,0 +1,218 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+struct Cell {
+    value: u32,
+    marked: bool,
+}
+
+impl Cell {
+    fn new(value: u32) -> Cell {
+        Cell {
+            value,
+            marked: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    numbers: Vec<Cell>,
+}
+
+impl Board {
+    const LINE_SIZE: usize = 5;
+
+    fn new_number(&mut self, value: u32) {
+        if let Some(c) = self.numbers.iter_mut().find(|c| c.value == value) {
+            c.marked = true;
+        }
+    }
+
+    fn has_line(&self) -> bool {
+        for row in self.numbers.chunks(Self::LINE_SIZE) {
+            if row.iter().all(|cell| cell.marked) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn has_column(&self) -> bool {
+        for column in 0..Self::LINE_SIZE {
+            if self
+                .numbers
+                .iter()
+                .skip(column)
+                .step_by(Self::LINE_SIZE)
+                .all(|f| f.marked)
+            {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn is_winner(&self) -> bool {
+        self.has_line() || self.has_column()
+    }
+
+    fn sum_non_marked(&self) -> u32 {
+        self.numbers
+            .iter()
+            .filter(|c| !c.marked)
+            .map(|c| c.value)
+            .sum()
+    }
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers: Vec<Cell> = s
+            .split_whitespace()
+            .map(|n| parse::value(n).map_err(|e| e.within(s, n)))
+            .map(|n| n.map(Cell::new))
+            .collect::<Result<_, _>>()?;
+
+        if numbers.len() != Self::LINE_SIZE * Self::LINE_SIZE {
+            let first_line = s.lines().next().unwrap_or_default();
+            return Err(ParseError::new(
+                format!("expected a {0}x{0} board", Self::LINE_SIZE),
+                first_line,
+            ));
+        }
+
+        Ok(Board { numbers })
+    }
+}
+
+fn solve_part1((values, boards): &(Vec<u32>, Vec<Board>)) -> u32 {
+    let mut boards = boards.clone();
+    for value in values {
+        for board in &mut boards {
+            board.new_number(*value);
+            if board.is_winner() {
+                return board.sum_non_marked() * value;
+            }
+        }
+    }
+
+    0
+}
+
+fn solve_part2((values, boards): &(Vec<u32>, Vec<Board>)) -> u32 {
+    let mut boards = boards.clone();
+    let n_boards = boards.len();
+    let mut winners = Vec::new();
+    for value in values {
+        for (index, board) in boards.iter_mut().enumerate() {
+            if winners.contains(&index) {
+                continue;
+            }
+            board.new_number(*value);
+            if board.is_winner() {
+                winners.push(index);
+                if winners.len() == n_boards {
+                    return board.sum_non_marked() * value;
+                }
+            }
+        }
+    }
+
+    0
+}
+
+fn prepare_input(input: &str) -> Result<(Vec<u32>, Vec<Board>), ParseError> {
+    let (first_line, boards) = parse::split_once(input.trim_end(), "\n")?;
+
+    let values: Vec<u32> = parse::separated(first_line, ',', parse::value)?;
+    let boards: Vec<Board> = boards
+        .trim_start()
+        .split("\n\n")
+        .map(|board| {
+            board
+                .parse()
+                .map_err(|e: ParseError| e.within(input, board))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((values, boards))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = (Vec<u32>, Vec<Board>);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&input), 4512);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&input), 1924);
+    }
+
+    #[test]
+    fn bad_board_number_is_located() {
+        let input = EXAMPLE.replace("10 16 15", "10 1x 15");
+        let error = Day4::parse(&input).unwrap_err();
+        assert_eq!((error.line(), error.column(), error.text()), (16, 4, "1x"));
+    }
+}
@@ -0,0 +1,178 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Clone, Copy)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = parse::split_once(s, ",")?;
+        let x = parse::value(x)?;
+        let y = parse::value(y).map_err(|e| e.within(s, y))?;
+        Ok(Point { x, y })
+    }
+}
+
+pub enum Dir {
+    Horizontal,
+    Vertical,
+    Diagonal,
+    None,
+}
+
+#[derive(Debug, Clone)]
+struct CoordsMap {
+    coords: HashMap<(i32, i32), i32>,
+}
+
+impl CoordsMap {
+    fn new() -> Self {
+        Self {
+            coords: HashMap::new(),
+        }
+    }
+
+    pub fn direction(&self, min: Point, max: Point, calc_diagonal: bool) -> Dir {
+        if min.x == max.x {
+            Dir::Vertical
+        } else if min.y == max.y {
+            Dir::Horizontal
+        } else if calc_diagonal {
+            Dir::Diagonal
+        } else {
+            Dir::None
+        }
+    }
+
+    pub fn add_point(&mut self, point: (i32, i32)) {
+        self.coords
+            .entry(point)
+            .and_modify(|e| *e += 1)
+            .or_insert(1);
+    }
+
+    fn add(&mut self, p1: Point, p2: Point, calc_diagonal: bool) {
+        let (min, max) = if p1 <= p2 { (p1, p2) } else { (p2, p1) };
+
+        match self.direction(min, max, calc_diagonal) {
+            Dir::Horizontal => {
+                for x in min.x..=max.x {
+                    self.add_point((x, min.y));
+                }
+            }
+            Dir::Vertical => {
+                for y in min.y..=max.y {
+                    self.add_point((min.x, y));
+                }
+            }
+            Dir::Diagonal => {
+                let inc = if min.y >= max.y { -1 } else { 1 };
+                let mut y = min.y;
+
+                for x in min.x..=max.x {
+                    self.add_point((x, y));
+                    y += inc;
+                }
+            }
+            Dir::None => (),
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.coords.values().filter(|&v| *v >= 2).count()
+    }
+}
+
+fn solve_part1(input: &[(Point, Point)]) -> usize {
+    let mut coords_map = CoordsMap::new();
+    for (p1, p2) in input {
+        coords_map.add(*p1, *p2, false)
+    }
+    coords_map.count()
+}
+
+fn solve_part2(input: &[(Point, Point)]) -> usize {
+    let mut coords_map = CoordsMap::new();
+    for (p1, p2) in input {
+        coords_map.add(*p1, *p2, true)
+    }
+    coords_map.count()
+}
+
+fn prepare_input(input: &str) -> Result<Vec<(Point, Point)>, ParseError> {
+    parse::lines(input, |line| {
+        let (p1, p2) = parse::split_once(line, "->")?;
+        let (p1, p2) = (p1.trim(), p2.trim());
+        let p1 = p1.parse().map_err(|e: ParseError| e.within(line, p1))?;
+        let p2 = p2.parse().map_err(|e: ParseError| e.within(line, p2))?;
+        Ok((p1, p2))
+    })
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Vec<(Point, Point)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&input), 5);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&input), 12);
+    }
+
+    #[test]
+    fn bad_point_is_located() {
+        let error = Day5::parse("0,9 -> 5,9\n8,0 -> 0,a8\n").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.text()), (2, 10, "a8"));
+    }
+}
@@ -0,0 +1,74 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+fn solve_parts(input: &[usize; 9], days: i32) -> usize {
+    let mut count = *input;
+
+    for _day in 0..days {
+        count.rotate_left(1);
+        count[6] += count[8];
+    }
+    count.iter().sum()
+}
+
+fn prepare_input(input: &str) -> Result<[usize; 9], ParseError> {
+    let mut counts = [0_usize; 9];
+
+    parse::separated(input, ',', |s| match parse::value::<usize>(s)? {
+        timer if timer < counts.len() => Ok(timer),
+        _ => Err(ParseError::new("timer out of range", s)),
+    })?
+    .into_iter()
+    .for_each(|c| {
+        counts[c] += 1;
+    });
+
+    Ok(counts)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = [usize; 9];
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_parts(input, 80)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_parts(input, 256)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3,4,3,1,2
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part1(&input), 5934);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&input), 26984457539);
+    }
+}
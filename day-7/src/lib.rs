@@ -0,0 +1,81 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+fn solve_part1(input: &[i32]) -> i32 {
+    let mut crabs = input.to_owned();
+    let middle = input.len() / 2;
+
+    crabs.select_nth_unstable(middle);
+    crabs.iter().map(|crap| (*crap - crabs[middle]).abs()).sum()
+}
+
+fn calc(target: i32, crabs: &[i32]) -> usize {
+    crabs
+        .iter()
+        .map(|crab| {
+            let crab_mean = (*crab - target).abs();
+            ((crab_mean * (crab_mean + 1)) / 2) as usize
+        })
+        .sum()
+}
+
+fn solve_part2(input: &[i32]) -> usize {
+    let crabs = input.to_owned();
+    let average: f64 = crabs.iter().map(|c| *c as f64).sum::<f64>() / crabs.len() as f64;
+
+    let with_ceil = calc(average.ceil() as i32, &crabs);
+    let with_floor = calc(average.floor() as i32, &crabs);
+
+    with_ceil.min(with_floor)
+}
+
+fn prepare_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    parse::separated(input, ',', parse::value)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+16,1,2,0,4,2,7,1,2,14
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&input), 37);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&input), 168);
+    }
+}
@@ -0,0 +1,140 @@
+use aoc_common::{
+    bundled_input,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use std::collections::HashSet;
+
+fn solve_part1(input: &[(String, String)]) -> usize {
+    input
+        .iter()
+        .flat_map(|(_, numbers)| numbers.split(" "))
+        .filter(|&s| matches!(s.len(), 2 | 3 | 4 | 7))
+        .count()
+}
+
+fn get_sixes(result: &mut [HashSet<u8>], sixes: Vec<HashSet<u8>>) {
+    for digit in sixes {
+        if !digit.is_superset(&result[1]) {
+            result[6] = digit;
+        } else if !digit.is_superset(&result[4]) {
+            result[0] = digit;
+        } else {
+            result[9] = digit;
+        }
+    }
+}
+
+fn get_fives(result: &mut [HashSet<u8>], fives: Vec<HashSet<u8>>) {
+    for digit in fives {
+        if result[6].is_superset(&digit) {
+            result[5] = digit;
+        } else if result[9].is_superset(&digit) {
+            result[3] = digit;
+        } else {
+            result[2] = digit;
+        }
+    }
+}
+
+fn find_digits(input: Vec<&str>) -> Vec<HashSet<u8>> {
+    let mut result = vec![HashSet::new(); 10];
+    let mut sixes: Vec<HashSet<u8>> = Vec::new();
+    let mut fives: Vec<HashSet<u8>> = Vec::new();
+
+    for code in input.iter() {
+        let digits: HashSet<u8> = code.bytes().collect();
+        match code.len() {
+            2 => result[1] = digits,
+            3 => result[7] = digits,
+            4 => result[4] = digits,
+            7 => result[8] = digits,
+
+            5 => fives.push(digits),
+            6 => sixes.push(digits),
+            _ => (),
+        };
+    }
+
+    get_sixes(&mut result, sixes);
+    get_fives(&mut result, fives);
+
+    result
+}
+
+fn solve_part2(input: &[(String, String)]) -> usize {
+    input
+        .iter()
+        .map(|(clue, numbers)| {
+            let decoded: Vec<HashSet<u8>> = find_digits(clue.split(" ").collect());
+            numbers.split(" ").fold(0, |total, s| {
+                let digits: HashSet<u8> = s.bytes().collect();
+                let digit = decoded
+                    .iter()
+                    .position(|d| *d == digits)
+                    .unwrap_or_default();
+                total * 10 + digit
+            })
+        })
+        .sum()
+}
+
+fn prepare_input(input: &str) -> Result<Vec<(String, String)>, ParseError> {
+    parse::lines(input.trim_end(), |line| {
+        let (clue, numbers) = parse::split_once(line, "|")?;
+        Ok((clue.to_owned(), numbers.to_owned()))
+    })
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Vec<(String, String)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part1(&input), 26);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part2(&input), 61229);
+    }
+}
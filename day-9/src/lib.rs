@@ -0,0 +1,229 @@
+use aoc_common::{
+    bundled_input,
+    grid::{Direction, Grid},
+    parse::ParseError,
+    solution::Solution,
+};
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+pub struct Cave {
+    heights: Grid<u8>,
+}
+
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            heights: Grid::single_ascii_number(input)?,
+        })
+    }
+}
+
+impl Cave {
+    fn low_point(&self, x: usize, y: usize) -> Option<u8> {
+        let current = *self.heights.get(x, y)?;
+        let low = self
+            .heights
+            .neighbors(x, y, Direction::Cardinal)
+            .all(|position| current < self.heights[position]);
+
+        if low {
+            Some(current)
+        } else {
+            None
+        }
+    }
+
+    /// Every low point, in reading order.
+    pub fn low_points(&self) -> Vec<(usize, usize)> {
+        self.heights
+            .coordinates()
+            .filter(|&(x, y)| self.low_point(x, y).is_some())
+            .collect()
+    }
+
+    /// Flood fills outwards from every low point, stopping at locations of height 9.
+    ///
+    /// Low points already reached from an earlier one share its basin, which
+    /// keeps the first low point in reading order.
+    pub fn basins(&self) -> Vec<Basin> {
+        let mut seen = Grid::new(self.heights.width(), self.heights.height());
+
+        self.low_points()
+            .into_iter()
+            .filter_map(|low_point| {
+                if seen[low_point] {
+                    return None;
+                }
+                let mut cells = vec![];
+                let mut queue = VecDeque::from([low_point]);
+                seen[low_point] = true;
+
+                while let Some((x, y)) = queue.pop_front() {
+                    cells.push((x, y));
+                    for next in self.heights.neighbors(x, y, Direction::Cardinal) {
+                        if self.heights[next] != 9 && !seen[next] {
+                            seen[next] = true;
+                            queue.push_back(next);
+                        }
+                    }
+                }
+
+                Some(Basin { low_point, cells })
+            })
+            .collect()
+    }
+
+    /// A map of the cave with each basin labelled by a letter and ridges as `#`.
+    pub fn basin_map<'a>(&'a self, basins: &'a [Basin]) -> BasinMap<'a> {
+        BasinMap { cave: self, basins }
+    }
+}
+
+/// Every location that flows down to the same low point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Basin {
+    pub low_point: (usize, usize),
+    /// The locations in the basin, in the order the flood fill reached them.
+    pub cells: Vec<(usize, usize)>,
+}
+
+impl Basin {
+    pub fn size(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+/// Renders basins over a cave, see [`Cave::basin_map`].
+///
+/// Labels go through `a`-`z` then `A`-`Z` and repeat from there, so neighbouring
+/// basins can share a label on very large maps.
+pub struct BasinMap<'a> {
+    cave: &'a Cave,
+    basins: &'a [Basin],
+}
+
+impl Display for BasinMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const LABELS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+        let heights = &self.cave.heights;
+        let mut map = Grid::with_data(
+            heights
+                .as_vec()
+                .iter()
+                .map(|&height| if height == 9 { '#' } else { '.' })
+                .collect(),
+            heights.width(),
+            heights.height(),
+        );
+        for (i, basin) in self.basins.iter().enumerate() {
+            for &cell in &basin.cells {
+                map[cell] = LABELS[i % LABELS.len()] as char;
+            }
+        }
+
+        map.fmt(f)
+    }
+}
+fn solve_part1(input: &Cave) -> usize {
+    input
+        .low_points()
+        .into_iter()
+        .map(|(x, y)| input.heights[(x, y)] as usize + 1)
+        .sum()
+}
+
+fn solve_part2(input: &Cave) -> usize {
+    let mut sizes: Vec<_> = input.basins().iter().map(Basin::size).collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes.iter().take(3).product()
+}
+
+fn prepare_input(input: &str) -> Result<Cave, ParseError> {
+    input.trim_end().parse()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = bundled_input!();
+
+    type Input = Cave;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&input), 15);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&input), 1134);
+    }
+
+    #[test]
+    fn basins_cover_every_location_below_9() {
+        let cave = Day9::parse(EXAMPLE).unwrap();
+        let basins = cave.basins();
+
+        let sizes: Vec<_> = basins.iter().map(Basin::size).collect();
+        assert_eq!(sizes, [3, 9, 14, 9]);
+        assert_eq!(
+            cave.basin_map(&basins).to_string(),
+            "\
+aa###bbbbb
+a#ccc#b#bb
+#ccccc#d#b
+ccccc#ddd#
+#c###ddddd
+"
+        );
+    }
+
+    #[test]
+    fn low_points_in_one_basin_share_it() {
+        let cave = Day9::parse("121\n999\n").unwrap();
+        assert_eq!(cave.low_points(), [(0, 0), (2, 0)]);
+
+        let basins = cave.basins();
+        assert_eq!(basins.len(), 1);
+        assert_eq!(basins[0].low_point, (0, 0));
+        assert_eq!(basins[0].size(), 3);
+        assert_eq!(cave.basin_map(&basins).to_string(), "aaa\n###\n");
+    }
+}